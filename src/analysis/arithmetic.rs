@@ -0,0 +1,59 @@
+use crate::parser::constant::{self, ConstantValue};
+use crate::parser::structures::symbol_table::SymbolType;
+use crate::parser::syntax_c1::NodeValue;
+use crate::{Loc, SyntaxTree};
+use std::collections::HashMap;
+
+/// Check an arithmetic operation whose operands have been parsed, the span refers to the operation in the source text.
+/// Operands that are constant are evaluated at compile time, so that a division by a constant zero or an integer
+/// operation on constant operands whose result does not fit into an Integer is found before the program runs. The
+/// values of named constants are given in `constants`, like [constant::evaluate] expects them.
+///
+/// # Error
+/// This function returns an error message that refers to the span if the operation always fails, otherwise it
+/// returns Ok.
+pub fn check_operation(
+    operation: &SyntaxTree<NodeValue>,
+    span: Loc,
+    constants: &HashMap<String, ConstantValue>,
+) -> Result<(), String> {
+    let children = operation.children();
+
+    if let NodeValue::Divide(_) = operation.value() {
+        let divisor = children
+            .get(1)
+            .and_then(|divisor| constant::evaluate(divisor, constants));
+        if divisor.is_some_and(|divisor| is_zero(&divisor)) {
+            return Err(format!("division by zero at {:?}", span));
+        }
+    }
+
+    let is_integer_operation = matches!(
+        operation.value(),
+        NodeValue::Plus(SymbolType::Integer)
+            | NodeValue::Minus(SymbolType::Integer)
+            | NodeValue::Times(SymbolType::Integer)
+            | NodeValue::Divide(SymbolType::Integer)
+            | NodeValue::UMinus(SymbolType::Integer)
+            | NodeValue::Cast(SymbolType::Integer, _)
+    );
+    let constant_operands = children
+        .iter()
+        .all(|child| constant::evaluate(child, constants).is_some());
+    // The evaluation of integer operations with constant operands only fails if the result is out of range
+    if is_integer_operation
+        && constant_operands
+        && constant::evaluate(operation, constants).is_none()
+    {
+        return Err(format!("integer overflow at {:?}", span));
+    }
+    Ok(())
+}
+
+/// Returns true if the given constant is a number equal to zero
+fn is_zero(value: &ConstantValue) -> bool {
+    match value {
+        ConstantValue::Float(v) => *v == 0.0,
+        _ => value.integer() == Some(0),
+    }
+}
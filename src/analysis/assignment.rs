@@ -0,0 +1,246 @@
+use super::flow::{condition, function_body};
+use crate::parser::syntax_c1::NodeValue;
+use crate::{Loc, SyntaxTree, Warning, ID};
+use std::collections::{HashMap, HashSet};
+
+/// The local variables that are definitely assigned at a point of the function, identified by the ids of their
+/// declarations. None if the point cannot be reached, so that any variable may be considered as assigned.
+type State = Option<HashSet<ID>>;
+
+/// Find the reads of local variables in the given function that are not definitely assigned, i.e., there is a path
+/// from the declaration of the variable to the read that does not assign a value to it. Parameters are assigned by the
+/// caller and global variables are initialized with zero, hence only variables declared in the function are checked.
+/// Each variable is reported once, at the statement of its first such read.
+pub fn unassigned_reads(function: &SyntaxTree<NodeValue>) -> Vec<Warning> {
+    let mut analysis = Analysis {
+        scopes: Vec::new(),
+        state: Some(HashSet::new()),
+        breaks: Vec::new(),
+        span: None,
+        reported: HashSet::new(),
+        warnings: Vec::new(),
+    };
+    analysis.statement(function_body(function));
+    analysis.warnings
+}
+
+struct Analysis {
+    // The local variables of each enclosing scope by name, with the ids of their declarations
+    scopes: Vec<HashMap<String, ID>>,
+    state: State,
+    // The states at the break statements of each enclosing switch statement
+    breaks: Vec<Vec<State>>,
+    // The span of the statement that is currently analyzed
+    span: Option<Loc>,
+    // The ids of the declarations of variables that have already been reported
+    reported: HashSet<ID>,
+    warnings: Vec<Warning>,
+}
+
+impl Analysis {
+    /// Analyze a statement, the state is updated to the state after the statement
+    fn statement(&mut self, statement: &SyntaxTree<NodeValue>) {
+        let outer_span = self.span;
+        if statement.span().is_some() {
+            self.span = statement.span();
+        }
+
+        let children = statement.children();
+        match statement.value() {
+            NodeValue::Sequence => {
+                self.scopes.push(HashMap::new());
+                children.iter().for_each(|child| self.statement(child));
+                self.scopes.pop();
+            }
+            NodeValue::If => {
+                self.expression(&children[0]);
+                let mut otherwise = self.state.clone();
+                match condition(&children[0]) {
+                    Some(true) => otherwise = None,
+                    Some(false) => self.state = None,
+                    None => {}
+                }
+                self.statement(&children[1]);
+                if let Some(branch) = children.get(2) {
+                    otherwise = self.branch(otherwise, branch);
+                }
+                self.state = merge(self.state.take(), otherwise);
+            }
+            NodeValue::While => self.repetition(&children[0], &children[1], None),
+            NodeValue::For => {
+                // The initialization belongs to the scope of the loop
+                self.scopes.push(HashMap::new());
+                self.statement(&children[0]);
+                self.repetition(&children[1], &children[3], Some(&children[2]));
+                self.scopes.pop();
+            }
+            NodeValue::DoWhile => {
+                // The body is executed at least once, before the condition is evaluated
+                self.statement(&children[1]);
+                self.expression(&children[0]);
+                if condition(&children[0]) == Some(true) {
+                    self.state = None;
+                }
+            }
+            NodeValue::Switch => {
+                self.expression(&children[0]);
+                // Without a default label, no label may be matched at all
+                let has_default = children[1..]
+                    .iter()
+                    .any(|label| matches!(label.value(), NodeValue::Default));
+                let unmatched = if has_default {
+                    None
+                } else {
+                    self.state.clone()
+                };
+
+                // Every label can be jumped to, or be reached by falling through from the previous one
+                let entry = self.state.clone();
+                self.breaks.push(Vec::new());
+                self.scopes.push(HashMap::new());
+                for label in &children[1..] {
+                    self.state = merge(self.state.take(), entry.clone());
+                    label
+                        .children()
+                        .iter()
+                        .for_each(|body| self.statement(body));
+                }
+                self.scopes.pop();
+                let breaks = self.breaks.pop().unwrap_or_default();
+                self.state = breaks
+                    .into_iter()
+                    .fold(merge(self.state.take(), unmatched), merge);
+            }
+            NodeValue::Break => {
+                let state = self.state.take();
+                if let Some(breaks) = self.breaks.last_mut() {
+                    breaks.push(state);
+                }
+            }
+            NodeValue::Return(_) => {
+                children.iter().for_each(|child| self.expression(child));
+                self.state = None;
+            }
+            _ => self.expression(statement),
+        }
+        self.span = outer_span;
+    }
+
+    /// Analyze a statement that is only executed if the given state holds, e.g., the else branch of an if statement.
+    /// Returns the state after the branch, the current state is left unchanged.
+    fn branch(&mut self, state: State, branch: &SyntaxTree<NodeValue>) -> State {
+        let current = std::mem::replace(&mut self.state, state);
+        self.statement(branch);
+        std::mem::replace(&mut self.state, current)
+    }
+
+    /// Analyze a loop that evaluates its condition before each execution of its body, followed by the step of a for
+    /// loop. The body may not be executed at all, so its assignments do not count after the loop.
+    fn repetition(
+        &mut self,
+        loop_condition: &SyntaxTree<NodeValue>,
+        body: &SyntaxTree<NodeValue>,
+        step: Option<&SyntaxTree<NodeValue>>,
+    ) {
+        self.expression(loop_condition);
+        let entry = match condition(loop_condition) {
+            Some(false) => None,
+            _ => self.state.clone(),
+        };
+        let body_state = self.branch(entry, body);
+        if let Some(step) = step {
+            self.branch(body_state, step);
+        }
+        // A loop with a constant true condition is never left, there are no breaks in loops
+        if condition(loop_condition) == Some(true) {
+            self.state = None;
+        }
+    }
+
+    /// Analyze an expression or a simple statement, e.g., a declaration or an assignment
+    fn expression(&mut self, expression: &SyntaxTree<NodeValue>) {
+        let children = expression.children();
+        match expression.value() {
+            NodeValue::VariableDeclaration(name, _, _, _, _) => {
+                if let Some(scope) = self.scopes.last_mut() {
+                    scope.insert(name.clone(), expression.id());
+                }
+                if let Some(state) = &mut self.state {
+                    state.remove(&expression.id());
+                }
+            }
+            NodeValue::VariableRef(name, _, _) => self.read(name),
+            NodeValue::Assign(_) => {
+                // An initialization starts with the declaration, the target precedes the assigned value
+                let (target, value) =
+                    (&children[children.len() - 2], &children[children.len() - 1]);
+                children[..children.len() - 2]
+                    .iter()
+                    .for_each(|child| self.expression(child));
+                self.expression(value);
+                self.assign(target);
+            }
+            NodeValue::LogAnd | NodeValue::LogOr => {
+                // The right operand is not evaluated if the left one already determines the result
+                self.expression(&children[0]);
+                let state = self.state.clone();
+                self.branch(state, &children[1]);
+            }
+            NodeValue::Conditional(_) => {
+                self.expression(&children[0]);
+                let state = self.state.clone();
+                let otherwise = self.branch(state, &children[2]);
+                self.expression(&children[1]);
+                self.state = merge(self.state.take(), otherwise);
+            }
+            _ => children.iter().for_each(|child| self.expression(child)),
+        }
+    }
+
+    /// Mark the variable that is the target of an assignment as assigned. Assigning a member of a struct counts as an
+    /// assignment of the struct variable.
+    fn assign(&mut self, target: &SyntaxTree<NodeValue>) {
+        match target.value() {
+            NodeValue::VariableRef(name, _, _) => {
+                if let (Some(id), Some(state)) = (self.lookup(name), &mut self.state) {
+                    state.insert(id);
+                }
+            }
+            NodeValue::MemberRef(_, _) => self.assign(&target.children()[0]),
+            _ => self.expression(target),
+        }
+    }
+
+    /// Check a read of the variable with the given name
+    fn read(&mut self, name: &str) {
+        let id = match self.lookup(name) {
+            Some(id) => id,
+            None => return,
+        };
+        let assigned = self.state.as_ref().is_none_or(|state| state.contains(&id));
+        if !assigned && self.reported.insert(id) {
+            self.warnings.push(Warning {
+                message: format!("variable {} may be used before it is assigned", name),
+                span: self.span,
+                related: None,
+            });
+        }
+    }
+
+    /// Return the id of the declaration of the local variable with the given name, or None if the name refers to a
+    /// parameter or a global variable
+    fn lookup(&self, name: &str) -> Option<ID> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(name).copied())
+    }
+}
+
+/// Merge the states of two paths that join, a variable is only assigned if it has been assigned on both paths
+fn merge(state: State, other: State) -> State {
+    match (state, other) {
+        (None, state) | (state, None) => state,
+        (Some(state), Some(other)) => Some(state.intersection(&other).copied().collect()),
+    }
+}
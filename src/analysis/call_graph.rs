@@ -0,0 +1,269 @@
+use crate::parser::structures::symbol_table::SymbolId;
+use crate::parser::syntax_c1::NodeValue;
+use crate::SyntaxTree;
+
+/// A function of a call graph with the functions it calls
+#[derive(Debug)]
+struct Function {
+    name: String,
+    id: SymbolId,
+    builtin: bool,
+    // The indices of the called functions in the order in which their names first appear in the function body
+    callees: Vec<usize>,
+}
+
+/// The call graph of a program, i.e., which function calls which other functions. It contains the declared functions
+/// and the builtin functions that are called. A function is identified by its symbol id, so a prototype and the
+/// definition of a function are the same node. The queries take the names of functions, since all functions are global.
+#[derive(Debug)]
+pub struct CallGraph {
+    // The functions in the order of their first declaration or call
+    functions: Vec<Function>,
+    // The indices of the functions that are called outside of functions, e.g., in the initialization of a global
+    // variable, they are called before `main`
+    global_callees: Vec<usize>,
+}
+
+impl CallGraph {
+    /// Build the call graph of the given program from the function calls in the bodies of its function definitions.
+    /// Calls outside of functions, e.g., in the initialization of a global variable, have no caller in the graph.
+    pub fn new(program: &SyntaxTree<NodeValue>) -> Self {
+        let mut graph = CallGraph {
+            functions: Vec::new(),
+            global_callees: Vec::new(),
+        };
+        graph.collect(program, None);
+        graph
+    }
+
+    /// Return the names of all functions of the graph in the order of their first declaration or call
+    pub fn functions(&self) -> Vec<&str> {
+        self.names((0..self.functions.len()).collect())
+    }
+
+    /// Return the names of the functions that are called by the function with the given name, in the order in which
+    /// they first appear in its body, e.g., `f` before `g` for `f(g())`. Returns an empty list for unknown functions.
+    pub fn callees(&self, name: &str) -> Vec<&str> {
+        match self.index(name) {
+            Some(index) => self.names(self.functions[index].callees.clone()),
+            None => Vec::new(),
+        }
+    }
+
+    /// Return the names of the functions that call the function with the given name, in the order of their
+    /// declaration. Returns an empty list for unknown functions.
+    pub fn callers(&self, name: &str) -> Vec<&str> {
+        let Some(index) = self.index(name) else {
+            return Vec::new();
+        };
+        let callers = (0..self.functions.len())
+            .filter(|caller| self.functions[*caller].callees.contains(&index))
+            .collect();
+        self.names(callers)
+    }
+
+    /// Return the strongly connected components of the graph, i.e., the groups of functions that can call each other.
+    /// A component is listed after all components it calls, its functions are in the order of their declaration.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<&str>> {
+        self.components()
+            .into_iter()
+            .map(|component| self.names(component))
+            .collect()
+    }
+
+    /// Return the components of functions that are recursive, i.e., a single function that calls itself or a group of
+    /// mutually recursive functions
+    pub fn recursions(&self) -> Vec<Vec<&str>> {
+        self.components()
+            .into_iter()
+            .filter(|component| self.is_cycle(component))
+            .map(|component| self.names(component))
+            .collect()
+    }
+
+    /// Returns true if the function with the given name calls itself, either directly or through other functions
+    pub fn is_recursive(&self, name: &str) -> bool {
+        let Some(index) = self.index(name) else {
+            return false;
+        };
+        self.components()
+            .iter()
+            .any(|component| component.contains(&index) && self.is_cycle(component))
+    }
+
+    /// Return the names of the declared functions that can never be called when the program runs, i.e., that cannot
+    /// be reached from `main` or from the initialization of a global variable in the graph. Without `main`, only the
+    /// functions called by initializations are reachable.
+    pub fn unreachable_from_main(&self) -> Vec<&str> {
+        let mut reachable = vec![false; self.functions.len()];
+        let mut pending: Vec<usize> = self.index("main").into_iter().collect();
+        pending.extend(&self.global_callees);
+        while let Some(index) = pending.pop() {
+            if !reachable[index] {
+                reachable[index] = true;
+                pending.extend(&self.functions[index].callees);
+            }
+        }
+        let unreachable = (0..self.functions.len())
+            .filter(|index| !reachable[*index] && !self.functions[*index].builtin)
+            .collect();
+        self.names(unreachable)
+    }
+
+    /// Export the graph in the DOT language of Graphviz, e.g., to render it for documentation. Builtin functions are
+    /// drawn with dashed lines.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph calls {\n");
+        for function in &self.functions {
+            if function.builtin {
+                dot += &format!("    \"{}\" [style=dashed];\n", function.name);
+            } else {
+                dot += &format!("    \"{}\";\n", function.name);
+            }
+        }
+        for function in &self.functions {
+            for callee in &function.callees {
+                let callee = &self.functions[*callee].name;
+                dot += &format!("    \"{}\" -> \"{}\";\n", function.name, callee);
+            }
+        }
+        dot += "}\n";
+        dot
+    }
+
+    /// Add the functions and calls of the given tree to the graph, the calls belong to the function with the given
+    /// index
+    fn collect(&mut self, tree: &SyntaxTree<NodeValue>, caller: Option<usize>) {
+        let caller = match tree.value() {
+            NodeValue::FunctionDeclaration(name, _, _, id) => Some(self.add(name, *id, false)),
+            NodeValue::FunctionPrototype(name, _, _, id) => {
+                self.add(name, *id, false);
+                None
+            }
+            NodeValue::FunctionCall(name, _, builtin, id) => {
+                let callee = self.add(name, *id, *builtin);
+                let callees = match caller {
+                    Some(caller) => &mut self.functions[caller].callees,
+                    None => &mut self.global_callees,
+                };
+                if !callees.contains(&callee) {
+                    callees.push(callee);
+                }
+                caller
+            }
+            _ => caller,
+        };
+        for child in tree.children() {
+            self.collect(child, caller);
+        }
+    }
+
+    /// Add the function with the given symbol id to the graph, unless it is already known, and return its index
+    fn add(&mut self, name: &str, id: SymbolId, builtin: bool) -> usize {
+        if let Some(index) = self.functions.iter().position(|f| f.id == id) {
+            return index;
+        }
+        self.functions.push(Function {
+            name: name.to_string(),
+            id,
+            builtin,
+            callees: Vec::new(),
+        });
+        self.functions.len() - 1
+    }
+
+    /// Return the index of the function with the given name
+    fn index(&self, name: &str) -> Option<usize> {
+        self.functions.iter().position(|f| f.name == name)
+    }
+
+    /// Return the names of the functions with the given indices
+    fn names(&self, indices: Vec<usize>) -> Vec<&str> {
+        indices
+            .into_iter()
+            .map(|index| self.functions[index].name.as_str())
+            .collect()
+    }
+
+    /// Returns true if the functions of the given component call each other, i.e., the component has more than one
+    /// function or its only function calls itself
+    fn is_cycle(&self, component: &[usize]) -> bool {
+        match component {
+            [index] => self.functions[*index].callees.contains(index),
+            _ => true,
+        }
+    }
+
+    /// Find the strongly connected components with Tarjan's algorithm, which finds a component only after the
+    /// components it calls
+    fn components(&self) -> Vec<Vec<usize>> {
+        let mut search = Search {
+            graph: self,
+            visited: 0,
+            order: vec![None; self.functions.len()],
+            low: vec![0; self.functions.len()],
+            stack: Vec::new(),
+            on_stack: vec![false; self.functions.len()],
+            components: Vec::new(),
+        };
+        for index in 0..self.functions.len() {
+            if search.order[index].is_none() {
+                search.visit(index);
+            }
+        }
+        search.components
+    }
+}
+
+/// The state of the depth-first search for strongly connected components
+struct Search<'a> {
+    graph: &'a CallGraph,
+    // The number of functions that have been visited so far
+    visited: usize,
+    // The order in which the functions have been visited
+    order: Vec<Option<usize>>,
+    // The lowest order of a function on the stack that is reachable from each function
+    low: Vec<usize>,
+    stack: Vec<usize>,
+    on_stack: Vec<bool>,
+    components: Vec<Vec<usize>>,
+}
+
+impl Search<'_> {
+    /// Visit the function with the given index and the functions it calls
+    fn visit(&mut self, index: usize) {
+        let order = self.visited;
+        self.visited += 1;
+        self.order[index] = Some(order);
+        self.low[index] = order;
+        self.stack.push(index);
+        self.on_stack[index] = true;
+
+        for &callee in &self.graph.functions[index].callees {
+            match self.order[callee] {
+                None => {
+                    self.visit(callee);
+                    self.low[index] = self.low[index].min(self.low[callee]);
+                }
+                Some(callee_order) if self.on_stack[callee] => {
+                    self.low[index] = self.low[index].min(callee_order);
+                }
+                Some(_) => {}
+            }
+        }
+
+        // The function is the first visited function of its component
+        if self.low[index] == order {
+            let mut component = Vec::new();
+            while let Some(member) = self.stack.pop() {
+                self.on_stack[member] = false;
+                component.push(member);
+                if member == index {
+                    break;
+                }
+            }
+            component.sort();
+            self.components.push(component);
+        }
+    }
+}
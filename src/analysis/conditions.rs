@@ -0,0 +1,45 @@
+use super::flow::condition;
+use crate::parser::structures::symbol_table::SymbolType;
+use crate::parser::syntax_c1::NodeValue;
+use crate::{Loc, SyntaxTree, Warning};
+
+/// Check the condition of an if statement or a loop, the span refers to the condition in the source text. Returns a
+/// warning if the condition is an assignment, which is likely meant to be a comparison, or if it always has the same
+/// value. A boolean literal is taken as deliberate, e.g., `while (true)`.
+pub fn suspicious_condition(condition_node: &SyntaxTree<NodeValue>, span: Loc) -> Option<Warning> {
+    let message = match condition_node.value() {
+        NodeValue::Assign(_) => {
+            String::from("assignment used as condition, did you mean to compare using ==?")
+        }
+        NodeValue::Boolean(_) => return None,
+        _ => format!("condition is always {}", condition(condition_node)?),
+    };
+    Some(Warning {
+        message,
+        span: Some(span),
+        related: None,
+    })
+}
+
+/// Check a comparison for equality or inequality, the span refers to the comparison in the source text. Returns a
+/// warning if one of the operands is a Float, since rounding errors make exact comparisons of Float values unreliable.
+pub fn float_equality(comparison: &SyntaxTree<NodeValue>, span: Loc) -> Option<Warning> {
+    let operator = match comparison.value() {
+        NodeValue::Eq => "==",
+        NodeValue::Neq => "!=",
+        _ => return None,
+    };
+    let is_float =
+        |operand: &SyntaxTree<NodeValue>| operand.value().symbol_type() == SymbolType::Float;
+    if !comparison.children().iter().any(is_float) {
+        return None;
+    }
+    Some(Warning {
+        message: format!(
+            "comparison of Float values using {} is unreliable",
+            operator
+        ),
+        span: Some(span),
+        related: None,
+    })
+}
@@ -0,0 +1,66 @@
+use crate::parser::constant::{self, ConstantValue};
+use crate::parser::syntax_c1::NodeValue;
+use crate::SyntaxTree;
+use std::collections::HashMap;
+
+/// Returns the body of the given function definition
+pub fn function_body(function: &SyntaxTree<NodeValue>) -> &SyntaxTree<NodeValue> {
+    // The body is the last child, it may be preceded by the parameters
+    function
+        .children()
+        .last()
+        .expect("a function definition has a body")
+}
+
+/// Returns true if control may reach the end of the given statement, i.e., the statement can complete normally.
+/// Returns false if every path through it ends in a return statement, a break, or an infinite loop.
+pub fn completes(statement: &SyntaxTree<NodeValue>) -> bool {
+    let children = statement.children();
+    match statement.value() {
+        NodeValue::Return(_) | NodeValue::Break => false,
+        NodeValue::Sequence => children.iter().all(completes),
+        NodeValue::If => match condition(&children[0]) {
+            Some(true) => completes(&children[1]),
+            Some(false) => children.get(2).is_none_or(completes),
+            None => completes(&children[1]) || children.get(2).is_none_or(completes),
+        },
+        // A loop only ends once its condition is false, break statements are not allowed in loops, not even in loops
+        // within a switch
+        NodeValue::While => condition(&children[0]) != Some(true),
+        NodeValue::For => condition(&children[1]) != Some(true),
+        NodeValue::DoWhile => completes(&children[1]) && condition(&children[0]) != Some(true),
+        NodeValue::Switch => {
+            // The labels fall through into each other, so that every label ends up in the statements of the last one,
+            // unless it breaks out of the switch. Without a default label, no label may be matched at all.
+            let labels = &children[1..];
+            let has_default = labels
+                .iter()
+                .any(|label| matches!(label.value(), NodeValue::Default));
+            let breaks = labels.iter().any(contains_break);
+            !has_default
+                || breaks
+                || labels
+                    .last()
+                    .is_none_or(|label| label.children().iter().all(completes))
+        }
+        _ => true,
+    }
+}
+
+/// Returns the value of a condition if it is a constant, otherwise None
+pub fn condition(expression: &SyntaxTree<NodeValue>) -> Option<bool> {
+    match constant::evaluate(expression, &HashMap::new()) {
+        Some(ConstantValue::Boolean(value)) => Some(value),
+        _ => None,
+    }
+}
+
+/// Returns true if the given statement contains a break that leaves the enclosing switch statement. Breaks in nested
+/// switch statements only leave those.
+fn contains_break(statement: &SyntaxTree<NodeValue>) -> bool {
+    match statement.value() {
+        NodeValue::Break => true,
+        NodeValue::Switch => false,
+        _ => statement.children().iter().any(contains_break),
+    }
+}
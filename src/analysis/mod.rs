@@ -0,0 +1,12 @@
+//! Analyses of the syntax tree that run after a part of the program has been parsed, e.g., a function definition.
+//! They only inspect the tree and report their findings, the tree itself is left unchanged.
+
+pub mod arithmetic;
+pub mod assignment;
+pub mod call_graph;
+pub mod conditions;
+mod flow;
+pub mod reachability;
+pub mod returns;
+pub mod shadowing;
+pub mod usage;
@@ -0,0 +1,119 @@
+use super::flow::{completes, condition, function_body};
+use crate::parser::syntax_c1::NodeValue;
+use crate::{Loc, SyntaxTree, Warning};
+
+/// Consecutive statements of a function that can never be executed, e.g., the statements following a return
+/// statement in the same sequence. Statements nested in these statements are not listed separately.
+#[derive(Debug)]
+pub struct UnreachableCode<'a> {
+    pub statements: &'a [SyntaxTree<NodeValue>],
+}
+
+impl UnreachableCode<'_> {
+    /// Return the range in the source text from the first to the last unreachable statement, if it is known
+    pub fn span(&self) -> Option<Loc> {
+        let begin = self.statements.first()?.span()?;
+        let end = self.statements.last()?.span()?;
+        Some(Loc {
+            begin: begin.begin,
+            end: end.end,
+        })
+    }
+
+    /// Create a warning about the unreachable statements
+    pub fn warning(&self) -> Warning {
+        Warning {
+            message: String::from("unreachable code"),
+            span: self.span(),
+            related: None,
+        }
+    }
+}
+
+/// Find the statements in the body of the given function that can never be executed. A statement is unreachable if
+/// it follows a statement that cannot complete, e.g., a return statement, or if it is a branch or loop body that is
+/// excluded by a constant condition, e.g., the else branch of `if (true)`.
+pub fn unreachable_code(function: &SyntaxTree<NodeValue>) -> Vec<UnreachableCode<'_>> {
+    let mut unreachable = Vec::new();
+    collect(function_body(function), &mut unreachable);
+    unreachable
+}
+
+/// Find the statements that can never be executed in all function definitions of the given program, e.g., the tree
+/// that [crate::Parser::do_parse] returns. The functions are visited in the order of their definition.
+pub fn unreachable_code_in_program(program: &SyntaxTree<NodeValue>) -> Vec<UnreachableCode<'_>> {
+    match program.value() {
+        NodeValue::FunctionDeclaration(_, _, _, _) => unreachable_code(program),
+        _ => program
+            .children()
+            .iter()
+            .flat_map(unreachable_code_in_program)
+            .collect(),
+    }
+}
+
+/// Collect the unreachable statements nested in the given reachable statement
+fn collect<'a>(statement: &'a SyntaxTree<NodeValue>, unreachable: &mut Vec<UnreachableCode<'a>>) {
+    let children = statement.children();
+    match statement.value() {
+        NodeValue::Sequence => {
+            for (index, child) in children.iter().enumerate() {
+                collect(child, unreachable);
+                if !completes(child) && index + 1 < children.len() {
+                    push(&children[index + 1..], unreachable);
+                    break;
+                }
+            }
+        }
+        NodeValue::If => {
+            let branches = &children[1..];
+            match condition(&children[0]) {
+                Some(true) => {
+                    collect(&branches[0], unreachable);
+                    push(&branches[1..], unreachable);
+                }
+                Some(false) => {
+                    push(&branches[..1], unreachable);
+                    branches[1..]
+                        .iter()
+                        .for_each(|branch| collect(branch, unreachable));
+                }
+                None => branches
+                    .iter()
+                    .for_each(|branch| collect(branch, unreachable)),
+            }
+        }
+        NodeValue::While | NodeValue::For => {
+            // The body is the last child, a for loop starts with its initialization instead of the condition
+            let (loop_condition, body) = match statement.value() {
+                NodeValue::While => (&children[0], &children[1..]),
+                _ => (&children[1], &children[3..]),
+            };
+            if condition(loop_condition) == Some(false) {
+                push(body, unreachable);
+            } else {
+                collect(&body[0], unreachable);
+            }
+        }
+        NodeValue::DoWhile => collect(&children[1], unreachable),
+        NodeValue::Switch => {
+            for label in &children[1..] {
+                label
+                    .children()
+                    .iter()
+                    .for_each(|body| collect(body, unreachable));
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Record the given statements as unreachable. Empty blocks are skipped, since there is nothing to execute.
+fn push<'a>(statements: &'a [SyntaxTree<NodeValue>], unreachable: &mut Vec<UnreachableCode<'a>>) {
+    let is_empty = |statement: &SyntaxTree<NodeValue>| {
+        matches!(statement.value(), NodeValue::Sequence) && statement.children().is_empty()
+    };
+    if !statements.iter().all(is_empty) {
+        unreachable.push(UnreachableCode { statements });
+    }
+}
@@ -0,0 +1,28 @@
+use super::flow::{completes, function_body};
+use crate::parser::structures::symbol_table::{Symbol, SymbolTable, SymbolType};
+use crate::parser::syntax_c1::NodeValue;
+use crate::{Error, SyntaxTree};
+
+/// Check that a non-void function returns a value on every path through its body, i.e., control cannot reach the end
+/// of the body without passing a return statement. The symbol table names the type of the function in the error.
+///
+/// # Error
+/// This function returns an Error result if the function may reach the end of its body, otherwise it returns Ok.
+pub fn check_returns(
+    function: &SyntaxTree<NodeValue>,
+    symbol: &Symbol,
+    symbol_table: &SymbolTable,
+) -> Result<(), Error> {
+    if symbol.symbol_type == SymbolType::Void {
+        return Ok(());
+    }
+
+    if !completes(function_body(function)) {
+        return Ok(());
+    }
+    Err(Error::Semantic(format!(
+        "function {} of type {} may reach its end without returning a value",
+        symbol.name,
+        symbol_table.symbol_type_name(symbol)
+    )))
+}
@@ -0,0 +1,31 @@
+use crate::parser::structures::symbol_table::{Symbol, SymbolClass};
+use crate::Warning;
+
+/// Create a warning about the declaration of the given symbol that shadows a symbol of an outer scope. The warning
+/// refers to the name of the new declaration and to the name of the shadowed declaration, if it is known, i.e., the
+/// shadowed symbol is not a builtin function.
+pub fn shadowing(symbol: &Symbol, shadowed: &Symbol) -> Warning {
+    Warning {
+        message: format!(
+            "{} {} shadows the {} of an outer scope",
+            kind(symbol),
+            symbol.name,
+            kind(shadowed)
+        ),
+        span: symbol.span,
+        related: shadowed.span,
+    }
+}
+
+/// Describe the kind of the given symbol, e.g., whether it is a variable or a function
+fn kind(symbol: &Symbol) -> &'static str {
+    match symbol.symbol_class {
+        SymbolClass::Function { builtin: true, .. } => "builtin function",
+        SymbolClass::Function { .. } => "function",
+        SymbolClass::Parameter => "parameter",
+        SymbolClass::Variable if symbol.is_const => "constant",
+        SymbolClass::Variable => "variable",
+        SymbolClass::Constant(_) => "enumerator",
+        SymbolClass::TypeAlias => "type",
+    }
+}
@@ -0,0 +1,63 @@
+use crate::parser::structures::symbol_table::{Symbol, SymbolClass};
+use crate::{CallGraph, Warning};
+
+/// Find the variables and parameters among the given symbols whose values are never read, e.g., the symbols of a scope
+/// that has been left. Symbols whose names start with an underscore are deliberately unused and not reported.
+pub fn unused_symbols(mut symbols: Vec<Symbol>) -> Vec<Warning> {
+    symbols.sort_by_key(|symbol| symbol.span.map(|span| span.begin));
+    symbols
+        .iter()
+        .filter(|symbol| symbol.reads == 0 && !symbol.name.starts_with('_'))
+        .filter_map(|symbol| {
+            let message = match symbol.symbol_class {
+                SymbolClass::Variable if symbol.writes > 0 => {
+                    format!("variable {} is assigned but never used", symbol.name)
+                }
+                SymbolClass::Variable => format!("unused variable {}", symbol.name),
+                SymbolClass::Parameter => format!("unused parameter {}", symbol.name),
+                _ => return None,
+            };
+            Some(Warning {
+                message,
+                span: symbol.span,
+                related: None,
+            })
+        })
+        .collect()
+}
+
+/// Find the functions among the given symbols that are never called when the program runs, i.e., that cannot be
+/// reached from `main` or from the initialization of a global variable in the call graph. This includes functions that
+/// only call each other, e.g., a pair of mutually recursive functions that is never called from `main`. The builtin
+/// functions and `main` are called by the runtime, functions whose names start with an underscore are deliberately
+/// unused and not reported.
+pub fn uncalled_functions<'a>(
+    symbols: impl Iterator<Item = &'a Symbol>,
+    call_graph: &CallGraph,
+) -> Vec<Warning> {
+    let unreachable = call_graph.unreachable_from_main();
+    let mut functions: Vec<&Symbol> = symbols
+        .filter(|symbol| symbol.is_function() && !symbol.is_builtin_function())
+        .filter(|symbol| unreachable.contains(&symbol.name.as_str()))
+        .filter(|symbol| symbol.name != "main" && !symbol.name.starts_with('_'))
+        .collect();
+    functions.sort_by_key(|symbol| symbol.span.map(|span| span.begin));
+    functions
+        .iter()
+        .map(|symbol| {
+            // Recursive calls do not count as calls of the function
+            let callers = call_graph.callers(&symbol.name);
+            let message = if callers.iter().all(|caller| *caller == symbol.name) {
+                format!("function {} is never called", symbol.name)
+            } else {
+                // The function is only called by functions that are never called themselves
+                format!("function {} is never called from main", symbol.name)
+            };
+            Warning {
+                message,
+                span: symbol.span,
+                related: None,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,1504 @@
+//! The type checker of C1. It walks the untyped syntax tree built by the parser, see [crate::Parser::do_parse_syntax],
+//! and checks it against the semantic rules of C1 with its own symbol table. The result is the typed tree, together
+//! with the warnings about the program, e.g., about unused variables or unreachable code.
+
+pub mod types;
+
+use crate::analysis::{
+    arithmetic, assignment, conditions, reachability, returns, shadowing, usage,
+};
+use crate::parser::constant::{self, ConstantValue};
+use crate::parser::structures::symbol_table::{
+    Symbol, SymbolClass, SymbolId, SymbolTable, SymbolType,
+};
+use crate::parser::syntax_c1::*;
+use crate::{AnalysisResult, CallGraph, Error, SyntaxTree, Warning};
+use std::collections::HashMap;
+use types::{explicit_cast_allowed, match_types, operator_type};
+
+type Tree = SyntaxTree<NodeValue>;
+
+/// Checks the semantics of a program given as syntax tree, see [TypeChecker::check]
+pub struct TypeChecker {
+    symbol_table: SymbolTable,
+    warnings: Vec<Warning>,
+    // The number of switch statements enclosing the current statement
+    switch_depth: usize,
+    // The names of called functions that had not been defined at the time of the call
+    undefined_calls: Vec<String>,
+    // The values of the constants whose initializers have been evaluated at compile time, e.g., `const int ZERO = 0;`
+    constant_values: HashMap<SymbolId, ConstantValue>,
+    /// Requires the initializers of global variables to be constant expressions, which are evaluated at compile time
+    pub strict_globals: bool,
+    /// Warns about declarations of variables and parameters that shadow a declaration of an outer scope
+    pub lint_shadowing: bool,
+}
+
+impl Default for TypeChecker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TypeChecker {
+    /// Create a type checker whose symbol table only contains the builtin functions
+    pub fn new() -> Self {
+        TypeChecker {
+            symbol_table: SymbolTable::with_builtins(),
+            warnings: Vec::new(),
+            switch_depth: 0,
+            undefined_calls: Vec::new(),
+            constant_values: HashMap::new(),
+            strict_globals: false,
+            lint_shadowing: false,
+        }
+    }
+
+    /// Check the program given as untyped syntax tree, i.e., a root node holding the program node like
+    /// [crate::Parser::do_parse_syntax] returns it. Only the declarations carry their declared types, the types of
+    /// expressions are derived by the checker. The tree is checked in the order of the source text and the first
+    /// violation of a semantic rule is reported.
+    ///
+    /// Returns the typed tree, i.e., the tree in which every expression carries its type, implicit conversions are
+    /// made explicit by cast nodes, and references to enumerators are replaced by their values.
+    pub fn check(self, tree: &SyntaxTree<NodeValue>) -> AnalysisResult {
+        self.check_with_warnings(tree).0
+    }
+
+    /// Like `check`, but also returns the warnings about the program, e.g., about unreachable code
+    pub fn check_with_warnings(
+        mut self,
+        tree: &SyntaxTree<NodeValue>,
+    ) -> (AnalysisResult, Vec<Warning>) {
+        match self.root(tree) {
+            Ok(tree) => (Ok(tree), self.warnings),
+            Err(error) => {
+                eprintln!("{}", error);
+                (Err(vec![error]), self.warnings)
+            }
+        }
+    }
+
+    /// Check the root or the program node, the program consists of a sequence of global declarations
+    fn root(&mut self, tree: &Tree) -> Result<Tree, Error> {
+        let mut node = copy(tree);
+        match tree.value() {
+            NodeValue::Root => {
+                for program in tree.children() {
+                    node.push_node(self.root(program)?);
+                }
+            }
+            NodeValue::Program => {
+                for declarations in tree.children() {
+                    let mut sequence = copy(declarations);
+                    for declaration in declarations.children() {
+                        sequence.push_node(self.global(declaration)?);
+                    }
+                    node.push_node(sequence);
+                }
+                self.check_main()?;
+                let call_graph = CallGraph::new(&node);
+                self.warnings.extend(usage::uncalled_functions(
+                    self.symbol_table.global_symbols(),
+                    &call_graph,
+                ));
+            }
+            _ => return Err(error("expected program".to_string())),
+        }
+        Ok(node)
+    }
+
+    /// Check a global declaration, e.g., a function definition or a global variable
+    fn global(&mut self, tree: &Tree) -> Result<Tree, Error> {
+        match tree.value() {
+            NodeValue::FunctionDeclaration(_, _, _, _) => self.function(tree),
+            NodeValue::FunctionPrototype(_, _, _, _) => self.function(tree),
+            NodeValue::StructDefinition(name) => {
+                let mut members = Vec::new();
+                for member in tree.children() {
+                    if let NodeValue::Member(member_name, symbol_type) = member.value() {
+                        if *symbol_type == SymbolType::Void {
+                            return Err(error(format!(
+                                "member {} cannot be of type void",
+                                member_name
+                            )));
+                        }
+                        members.push((member_name.clone(), *symbol_type));
+                    }
+                }
+                self.symbol_table.define_struct(name.clone(), members)?;
+                Ok(tree.clone())
+            }
+            NodeValue::TypeAlias(name, symbol_type) => {
+                if let Some(previous) = self.symbol_table.get(name).filter(|s| s.is_type_alias()) {
+                    return Err(error(format!(
+                        "type {} has already been defined as {}",
+                        name,
+                        self.symbol_table.type_name(previous.symbol_type)
+                    )));
+                }
+                let symbol = self
+                    .symbol_table
+                    .type_alias_symbol(name.clone(), *symbol_type);
+                self.symbol_table.insert(symbol)?;
+                Ok(tree.clone())
+            }
+            _ if self.strict_globals => {
+                // Global variables are initialized at compile time, hence their initializers must be constant
+                let node = self.statement(tree)?;
+                self.evaluate_global_declaration(node).map_err(error)
+            }
+            _ => self.statement(tree),
+        }
+    }
+
+    /// Check a function definition or prototype. The parameters and the body share the scope of the function.
+    fn function(&mut self, tree: &Tree) -> Result<Tree, Error> {
+        let (name, symbol_type, alias, is_definition) = match tree.value() {
+            NodeValue::FunctionDeclaration(name, symbol_type, alias, _) => {
+                (name, *symbol_type, alias, true)
+            }
+            NodeValue::FunctionPrototype(name, symbol_type, alias, _) => {
+                (name, *symbol_type, alias, false)
+            }
+            _ => unreachable!("only functions are checked as functions"),
+        };
+
+        // A function may already have been declared, e.g. by a prototype
+        let previous = self
+            .symbol_table
+            .get(name)
+            .filter(|s| s.is_function())
+            .cloned();
+        if previous.as_ref().is_some_and(|f| f.is_builtin_function()) {
+            return Err(error(format!("cannot redefine builtin function {}", name)));
+        }
+        let mut symbol = self.symbol_table.function_symbol(name.clone(), symbol_type);
+        symbol.type_alias = alias.clone();
+        symbol.span = tree.span();
+        let id = match self.symbol_table.insert(symbol) {
+            Ok(id) => id,
+            // Only a declaration that is no function can collide with a function
+            Err(_) => return Err(error(format!("{} has already been declared", name))),
+        };
+        self.symbol_table.enter_scope();
+        let mut node = if is_definition {
+            function_node(name.clone(), symbol_type, alias.clone(), id)
+        } else {
+            function_prototype_node(name.clone(), symbol_type, alias.clone(), id)
+        };
+        if let Some(span) = tree.span() {
+            node.set_span(span);
+        }
+
+        let (parameters, body) = match tree.children().split_last() {
+            Some((body, parameters)) if is_definition => (parameters, Some(body)),
+            _ => (&tree.children()[..], None),
+        };
+        for parameters in parameters {
+            node.push_node(self.parameters(parameters)?);
+        }
+        if let Some(previous) = previous {
+            let function = self.symbol_table.current_function().unwrap();
+            if !function.has_same_signature(&previous) {
+                return Err(error(format!(
+                    "declaration of function {} does not match its previous declaration",
+                    &function.name
+                )));
+            }
+        }
+
+        let body = match body {
+            Some(body) => self.statements(body)?,
+            None => {
+                // The parameters of a prototype are only names, they are not expected to be used
+                self.symbol_table.leave_scope();
+                return Ok(node);
+            }
+        };
+        node.push_node(body);
+        self.leave_scope();
+
+        self.symbol_table
+            .define_function(name)
+            .map_err(|_| error(format!("function {} has already been defined", name)))?;
+        // A non-void function must not reach the end of its body
+        let symbol = self.symbol_table.get(name).unwrap();
+        returns::check_returns(&node, symbol, &self.symbol_table)?;
+        for code in reachability::unreachable_code(&node) {
+            self.warnings.push(code.warning());
+        }
+        self.warnings.extend(assignment::unassigned_reads(&node));
+        Ok(node)
+    }
+
+    /// Check the sequence of parameters of a function and declare them in the scope of the function
+    fn parameters(&mut self, tree: &Tree) -> Result<Tree, Error> {
+        let mut node = copy(tree);
+        for parameter in tree.children() {
+            if let NodeValue::Parameter(name, symbol_type, alias, _) = parameter.value() {
+                if *symbol_type == SymbolType::Void {
+                    return Err(error("parameter cannot be of type void".to_string()));
+                }
+                let mut symbol = self
+                    .symbol_table
+                    .parameter_symbol(name.clone(), *symbol_type);
+                symbol.type_alias = alias.clone();
+                symbol.span = parameter.span();
+                self.check_shadowing(&symbol);
+                let mut parameter_node = match self.symbol_table.insert(symbol) {
+                    Ok(id) => parameter_node(name.clone(), *symbol_type, alias.clone(), id),
+                    Err(_) => return Err(error("incorrect parameter".to_string())),
+                };
+                if let Some(span) = parameter.span() {
+                    parameter_node.set_span(span);
+                }
+                node.push_node(parameter_node);
+            }
+        }
+        Ok(node)
+    }
+
+    /// Check that there is a parameterless function `void main()` and that every called function has been defined
+    fn check_main(&mut self) -> Result<(), Error> {
+        match self.symbol_table.get("main") {
+            Some(symbol) => {
+                if let SymbolClass::Function {
+                    parameters,
+                    defined,
+                    ..
+                } = &symbol.symbol_class
+                {
+                    if !defined {
+                        return Err(error(
+                            "void main() is declared but never defined".to_string(),
+                        ));
+                    }
+                    if symbol.symbol_type != SymbolType::Void {
+                        return Err(error("main() must be of type void".to_string()));
+                    }
+                    if !parameters.is_empty() {
+                        return Err(error("void main() cannot have parameters".to_string()));
+                    }
+                } else {
+                    return Err(error("void main() doesn't exist".to_string()));
+                }
+            }
+            None => return Err(error("void main() doesn't exist".to_string())),
+        }
+
+        // Functions that are called must be defined eventually, a prototype is not sufficient
+        for name in std::mem::take(&mut self.undefined_calls) {
+            if let Some(function) = self
+                .symbol_table
+                .get(&name)
+                .filter(|f| !f.is_defined_function())
+            {
+                return Err(error(format!(
+                    "function {} is called but never defined",
+                    &function.name
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Leave the current scope of the symbol table and warn about its variables and parameters that are never used
+    fn leave_scope(&mut self) {
+        let symbols = self.symbol_table.leave_scope();
+        self.warnings.extend(usage::unused_symbols(symbols));
+    }
+
+    /// Warn about the given symbol if it shadows a symbol of an outer scope and the lint is enabled
+    fn check_shadowing(&mut self, symbol: &Symbol) {
+        if !self.lint_shadowing {
+            return;
+        }
+        if let Some(shadowed) = self.symbol_table.get_outer(&symbol.name) {
+            self.warnings.push(shadowing::shadowing(symbol, shadowed));
+        }
+    }
+
+    /// Check the condition of an if statement or a loop, it must be boolean. Conditions that are suspicious, e.g.,
+    /// always true, are reported as warnings.
+    fn check_condition(&mut self, condition: &Tree) -> Result<(), Error> {
+        if condition.value().symbol_type() != SymbolType::Boolean {
+            return Err(error("condition must be boolean".to_string()));
+        }
+        self.warnings.extend(conditions::suspicious_condition(
+            condition,
+            condition.span().unwrap_or_default(),
+        ));
+        Ok(())
+    }
+
+    /// Check the statements of a sequence in the current scope, e.g., the body of a function
+    fn statements(&mut self, tree: &Tree) -> Result<Tree, Error> {
+        let mut node = copy(tree);
+        for statement in tree.children() {
+            node.push_node(self.statement(statement)?);
+        }
+        Ok(node)
+    }
+
+    /// Check a statement that has a scope of its own, e.g., the body of a loop
+    fn body(&mut self, tree: &Tree) -> Result<Tree, Error> {
+        self.symbol_table.enter_scope();
+        let node = self.statement(tree)?;
+        self.leave_scope();
+        Ok(node)
+    }
+
+    /// Check the body of a loop. Break statements are not allowed in loops, not even in a loop within a switch, as
+    /// they would leave the loop rather than the switch.
+    fn loop_body(&mut self, tree: &Tree) -> Result<Tree, Error> {
+        let switch_depth = std::mem::take(&mut self.switch_depth);
+        let node = self.body(tree)?;
+        self.switch_depth = switch_depth;
+        Ok(node)
+    }
+
+    /// Check a statement
+    fn statement(&mut self, tree: &Tree) -> Result<Tree, Error> {
+        let children = tree.children();
+        let mut node = copy(tree);
+        match tree.value() {
+            NodeValue::Sequence => {
+                // It's a block
+                self.symbol_table.enter_scope();
+                node = self.statements(tree)?;
+                self.leave_scope();
+            }
+            NodeValue::If => {
+                let condition = self.expression(&children[0])?;
+                let branches = children[1..]
+                    .iter()
+                    .map(|branch| self.body(branch))
+                    .collect::<Result<Vec<_>, _>>()?;
+                self.check_condition(&condition)?;
+                node.push_node(condition);
+                branches
+                    .into_iter()
+                    .for_each(|branch| node.push_node(branch));
+            }
+            NodeValue::While | NodeValue::DoWhile => {
+                // The body of a do-while loop precedes its condition in the source text
+                let (condition, body) = if let NodeValue::While = tree.value() {
+                    let condition = self.expression(&children[0])?;
+                    (condition, self.loop_body(&children[1])?)
+                } else {
+                    let body = self.loop_body(&children[1])?;
+                    (self.expression(&children[0])?, body)
+                };
+                self.check_condition(&condition)?;
+                node.push_node(condition);
+                node.push_node(body);
+            }
+            NodeValue::For => {
+                // The initialization belongs to the scope of the loop
+                self.symbol_table.enter_scope();
+                let init = self.statement(&children[0])?;
+                let condition = self.expression(&children[1])?;
+                let step = self.statement(&children[2])?;
+                let body = self.loop_body(&children[3])?;
+                self.check_condition(&condition)?;
+                self.leave_scope();
+                for child in [init, condition, step, body] {
+                    node.push_node(child);
+                }
+            }
+            NodeValue::Switch => node = self.switch(tree)?,
+            NodeValue::Break => {
+                if self.switch_depth == 0 {
+                    return Err(error("break statement not within switch".to_string()));
+                }
+            }
+            NodeValue::Return(_) => node = self.return_statement(tree)?,
+            NodeValue::Print(_) => node = self.print(tree)?,
+            NodeValue::DeclarationList(_, _) => {
+                for declarator in children {
+                    node.push_node(self.declaration(declarator)?);
+                }
+            }
+            NodeValue::VariableDeclaration(_, _, _, _, _) => node = self.declaration(tree)?,
+            NodeValue::Assign(_) if is_initialization(tree) => node = self.declaration(tree)?,
+            NodeValue::EnumDefinition(tag) => {
+                self.symbol_table.define_enum(tag.clone())?;
+                // The enumerators are numbered from zero, unless a value is given explicitly
+                let mut next_value = Some(0);
+                for enumerator in children {
+                    if let NodeValue::Enumerator(name, _, _) = enumerator.value() {
+                        let value = match enumerator.children().first() {
+                            Some(value) => {
+                                let value = self.expression(value)?;
+                                integer_constant(&value).ok_or_else(|| {
+                                    error(format!(
+                                        "value of enumerator {} must be an integer constant, got {}",
+                                        name,
+                                        value.value()
+                                    ))
+                                })?
+                            }
+                            None => next_value.ok_or_else(|| {
+                                error(format!("value of enumerator {} is out of range", name))
+                            })?,
+                        };
+                        let symbol = self.symbol_table.enumerator_symbol(name.clone(), value);
+                        let id = self
+                            .symbol_table
+                            .insert(symbol)
+                            .map_err(|_| error(format!("{} has already been declared", name)))?;
+                        next_value = value.checked_add(1);
+                        node.push_node(enumerator_node(name.clone(), value, id));
+                    }
+                }
+            }
+            _ => node = self.expression(tree)?,
+        }
+        if let Some(span) = tree.span() {
+            node.set_span(span);
+        }
+        Ok(node)
+    }
+
+    /// Check a switch statement. The labels share the scope of the switch, their statements follow each other.
+    fn switch(&mut self, tree: &Tree) -> Result<Tree, Error> {
+        let children = tree.children();
+        let mut node = copy(tree);
+        let scrutinee = self.expression(&children[0])?;
+
+        self.symbol_table.enter_scope();
+        self.switch_depth += 1;
+        let mut labels = Vec::new();
+        for label in &children[1..] {
+            let (mut label_node, body) = match (label.value(), label.children().as_slice()) {
+                (NodeValue::Case(_), [value, body]) => {
+                    let value = self.expression(value)?;
+                    match integer_constant(&value) {
+                        Some(value) => (case_node(value), body),
+                        None => {
+                            return Err(error(format!(
+                                "case label must be an integer constant, got {}",
+                                value.value()
+                            )))
+                        }
+                    }
+                }
+                (_, [body]) => (copy(label), body),
+                _ => return Err(error(format!("unexpected {} in switch", label.value()))),
+            };
+            label_node.push_node(self.statements(body)?);
+            labels.push(label_node);
+        }
+
+        let scrutinee_type = scrutinee.value().symbol_type();
+        if scrutinee_type != SymbolType::Integer {
+            return Err(error(format!(
+                "switch expression must be of type Integer, got {}",
+                self.symbol_table.type_name(scrutinee_type)
+            )));
+        }
+        node.push_node(scrutinee);
+        let mut values: Vec<i32> = Vec::new();
+        let mut has_default = false;
+        for label in labels {
+            match label.value() {
+                NodeValue::Case(value) => {
+                    if values.contains(value) {
+                        return Err(error(format!("duplicate case label {}", value)));
+                    }
+                    values.push(*value);
+                }
+                _ => {
+                    if has_default {
+                        return Err(error("multiple default labels in one switch".to_string()));
+                    }
+                    has_default = true;
+                }
+            }
+            node.push_node(label);
+        }
+        self.switch_depth -= 1;
+        self.leave_scope();
+        Ok(node)
+    }
+
+    /// Check a return statement against the return type of the current function
+    fn return_statement(&mut self, tree: &Tree) -> Result<Tree, Error> {
+        let function_type = self.symbol_table.function_type().unwrap();
+        let value = match tree.children().first() {
+            Some(value) => self.expression(value)?,
+            None if function_type == SymbolType::Void => return Ok(return_node(SymbolType::Void)),
+            None => return Err(error("return must be of type void".to_string())),
+        };
+
+        let return_type = value.value().symbol_type();
+        if !match_types(function_type, return_type) {
+            let function_type_name = self
+                .symbol_table
+                .current_function()
+                .map_or(function_type.to_string(), |f| {
+                    self.symbol_table.symbol_type_name(f)
+                });
+            return Err(error(format!(
+                "expected return type {}, got {}",
+                function_type_name,
+                self.symbol_table.type_name(return_type)
+            )));
+        }
+        let mut node = return_node(return_type);
+        node.push_node(convert(value, function_type));
+        Ok(node)
+    }
+
+    /// Check the arguments of a print statement. A formatted output is given with its format string as first of
+    /// multiple children, the arguments must match the format specifiers of the string.
+    fn print(&mut self, tree: &Tree) -> Result<Tree, Error> {
+        let (format, args) = match tree.children().as_slice() {
+            [format, args @ ..] if !args.is_empty() => (Some(format), args),
+            args => (None, args),
+        };
+        let args = args
+            .iter()
+            .map(|arg| self.expression(arg))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let format = match format.map(|format| format.value()) {
+            Some(NodeValue::String(format)) => format,
+            Some(other) => return Err(error(format!("expected format string, got {}", other))),
+            None => {
+                // Without a format string, the single argument is printed as it is. The placeholders of a string
+                // literal lack their arguments nevertheless, e.g. `printf("%d")`, any other `%` is plain text
+                if let Some(NodeValue::String(text)) = args.first().map(|arg| arg.value()) {
+                    let expected = placeholder_count(text);
+                    if expected > 0 {
+                        return Err(argument_count_error(text, expected, 0));
+                    }
+                }
+                match args.first().map(|arg| arg.value().symbol_type()) {
+                    Some(SymbolType::Void) => {
+                        return Err(error("type void not acceptable output".to_string()))
+                    }
+                    Some(SymbolType::Struct(_)) => {
+                        return Err(error("type struct not acceptable output".to_string()))
+                    }
+                    _ => {}
+                }
+                let mut node = print_node();
+                args.into_iter().for_each(|arg| node.push_node(arg));
+                return Ok(node);
+            }
+        };
+
+        let segments = parse_format_string(format).map_err(error)?;
+        let expected = format_arguments(&segments);
+        if expected.len() != args.len() {
+            return Err(argument_count_error(format, expected.len(), args.len()));
+        }
+        for (expected_type, arg) in expected.iter().zip(&args) {
+            let arg_type = arg.value().symbol_type();
+            if *expected_type != arg_type {
+                let specifier = FormatSegment::specifier(*expected_type).unwrap();
+                return Err(error(format!(
+                    "format specifier {} expects {}, got {}",
+                    specifier,
+                    expected_type,
+                    self.symbol_table.type_name(arg_type)
+                )));
+            }
+        }
+        let mut node = formatted_print_node(segments);
+        args.into_iter().for_each(|arg| node.push_node(arg));
+        Ok(node)
+    }
+
+    /// Check the declaration of a single variable or constant, with or without its initialization. The variable is
+    /// declared before its initializer is checked, like in the source text.
+    fn declaration(&mut self, tree: &Tree) -> Result<Tree, Error> {
+        let declaration = if is_initialization(tree) {
+            &tree.children()[0]
+        } else {
+            tree
+        };
+        let (name, symbol_type, alias, is_const) = match declaration.value() {
+            NodeValue::VariableDeclaration(name, symbol_type, alias, is_const, _) => {
+                (name, *symbol_type, alias, *is_const)
+            }
+            _ => return self.statement(tree),
+        };
+        if is_const && !is_initialization(tree) {
+            return Err(error(format!(
+                "constant {} must be initialized at its declaration",
+                name
+            )));
+        }
+
+        let mut symbol = if is_const {
+            self.symbol_table.constant_symbol(name.clone(), symbol_type)
+        } else {
+            self.symbol_table.variable_symbol(name.clone(), symbol_type)
+        };
+        symbol.type_alias = alias.clone();
+        symbol.span = declaration.span();
+        self.check_shadowing(&symbol);
+        let id = self.symbol_table.insert(symbol)?;
+        let mut declaration_node =
+            variable_node(name.clone(), symbol_type, alias.clone(), is_const, id);
+        if let Some(span) = declaration.span() {
+            declaration_node.set_span(span);
+        }
+        if !is_initialization(tree) {
+            return Ok(declaration_node);
+        }
+
+        let value = self.expression(&tree.children()[2])?;
+        if is_const {
+            // The value of the constant is known to the operations that refer to it, if it can be evaluated
+            if let Some(constant) = constant::evaluate(&value, &self.known_constants(&value)) {
+                self.constant_values.insert(id, constant);
+            }
+        }
+        let mut node = self.assign_variable(name, value)?;
+        node.prepend_node(declaration_node);
+        Ok(node)
+    }
+
+    /// Evaluate the initializers of a global declaration and replace them by their values. The declarators are
+    /// evaluated in order, so that an initializer can refer to the global constants declared before it.
+    fn evaluate_global_declaration(&mut self, declaration: Tree) -> Result<Tree, String> {
+        match declaration.value() {
+            NodeValue::DeclarationList(_, _) => {
+                let mut list = copy(&declaration);
+                for declarator in declaration.children() {
+                    list.push_node(self.evaluate_global_declaration(declarator.clone())?);
+                }
+                Ok(list)
+            }
+            NodeValue::Assign(symbol_type) if is_initialization(&declaration) => {
+                // The children are the declaration, the reference to the variable and the initializer
+                let children = declaration.children();
+                let name = children[0].value().symbol_name().unwrap();
+                let value =
+                    match constant::evaluate(&children[2], &self.known_constants(&children[2])) {
+                        Some(value) => value,
+                        None => {
+                            return Err(format!(
+                            "initializer of global variable {} is not a constant expression: {}",
+                            name,
+                            children[2].value()
+                        ));
+                        }
+                    };
+                let mut assignment = assign_node(*symbol_type);
+                if let Some(span) = declaration.span() {
+                    assignment.set_span(span);
+                }
+                assignment.push_node(children[0].clone());
+                assignment.push_node(children[1].clone());
+                assignment.push_node(value.node());
+                Ok(assignment)
+            }
+            _ => Ok(declaration),
+        }
+    }
+
+    /// Return the values of the constants that the given typed expression refers to, keyed by their names like
+    /// [constant::evaluate] expects them. The references are resolved, hence a variable that shadows a constant of the
+    /// same name is not mistaken for it.
+    fn known_constants(&self, tree: &Tree) -> HashMap<String, ConstantValue> {
+        let mut constants = HashMap::new();
+        let mut pending = vec![tree];
+        while let Some(node) = pending.pop() {
+            if let NodeValue::VariableRef(name, _, id) = node.value() {
+                if let Some(value) = self.constant_values.get(id) {
+                    constants.insert(name.clone(), value.clone());
+                }
+            }
+            pending.extend(node.children());
+        }
+        constants
+    }
+
+    /// Check an expression and return its typed tree
+    fn expression(&mut self, tree: &Tree) -> Result<Tree, Error> {
+        let children = tree.children();
+        let mut node = copy(tree);
+        match tree.value() {
+            NodeValue::Integer(_)
+            | NodeValue::Float(_)
+            | NodeValue::Boolean(_)
+            | NodeValue::String(_)
+            | NodeValue::Char(_) => {}
+            NodeValue::VariableRef(name, _, _) | NodeValue::ConstantRef(name, _, _) => {
+                self.symbol_table.record_read(name);
+                node = match self.symbol_table.get(name) {
+                    None => return Err(error(format!("Undeclared symbol {}", name))),
+                    // A named constant is replaced by its value, but the name is kept for reference
+                    Some(symbol) => match &symbol.symbol_class {
+                        SymbolClass::Constant(value) => {
+                            constant_ref_node(name.clone(), *value, symbol.id())
+                        }
+                        // A function can only be called, it has no value of its own
+                        SymbolClass::Function { .. } => {
+                            return Err(error(format!("cannot use function {} as a value", name)))
+                        }
+                        _ => variable_ref_node(name.clone(), symbol.symbol_type, symbol.id()),
+                    },
+                };
+            }
+            NodeValue::MemberRef(_, _) => {
+                node = self.member_access(tree)?;
+                // Reading a member reads the accessed struct variable
+                if let Some(name) = root_variable(&node) {
+                    self.symbol_table.record_read(&name);
+                }
+            }
+            NodeValue::FunctionCall(name, _, _, _) => node = self.call(name, tree)?,
+            NodeValue::Assign(_) => {
+                let (target, value) = (&children[0], &children[1]);
+                node = match target.value() {
+                    NodeValue::MemberRef(_, _) => {
+                        let target = self.member_access(target)?;
+                        let value = self.expression(value)?;
+                        self.assign_member(target, value)?
+                    }
+                    _ => {
+                        let value = self.expression(value)?;
+                        let name = target.value().symbol_name().unwrap_or_default();
+                        // Constants can only be assigned once, i.e., when they are initialized at their declaration
+                        if let Some(symbol) = self.symbol_table.get(&name).filter(|s| s.is_const) {
+                            if let SymbolClass::Constant(_) = symbol.symbol_class {
+                                return Err(error(format!(
+                                    "cannot assign to enumerator {}",
+                                    &symbol.name
+                                )));
+                            }
+                            return Err(error(format!(
+                                "cannot assign to constant {}",
+                                &symbol.name
+                            )));
+                        }
+                        self.assign_variable(&name, value)?
+                    }
+                };
+            }
+            NodeValue::Cast(target_type, _) => {
+                let operand = self.expression(&children[0])?;
+                let source_type = operand.value().symbol_type();
+                if !explicit_cast_allowed(source_type, *target_type) {
+                    return Err(error(format!(
+                        "cannot cast {} to {}",
+                        self.symbol_table.type_name(source_type),
+                        self.symbol_table.type_name(*target_type)
+                    )));
+                }
+                node.push_node(operand);
+                if *target_type == SymbolType::Integer {
+                    let constants = self.known_constants(&node);
+                    arithmetic::check_operation(&node, tree.span().unwrap_or_default(), &constants)
+                        .map_err(error)?;
+                }
+            }
+            NodeValue::UMinus(_) => {
+                let operand = self.expression(&children[0])?;
+                node = match operand.value().symbol_type() {
+                    // A character is promoted to an integer
+                    SymbolType::Integer | SymbolType::Char => u_minus_node(SymbolType::Integer),
+                    SymbolType::Float => u_minus_node(SymbolType::Float),
+                    other => {
+                        return Err(error(format!(
+                            "expected symbol type integer or float, got {}",
+                            self.symbol_table.type_name(other)
+                        )))
+                    }
+                };
+                node.push_node(operand);
+                if node.value().symbol_type() == SymbolType::Integer {
+                    let constants = self.known_constants(&node);
+                    arithmetic::check_operation(&node, tree.span().unwrap_or_default(), &constants)
+                        .map_err(error)?;
+                }
+            }
+            NodeValue::Plus(_)
+            | NodeValue::Minus(_)
+            | NodeValue::Times(_)
+            | NodeValue::Divide(_)
+            | NodeValue::Eq
+            | NodeValue::Neq
+            | NodeValue::Leq
+            | NodeValue::Geq
+            | NodeValue::Lst
+            | NodeValue::Grt
+            | NodeValue::LogOr
+            | NodeValue::LogAnd => {
+                let lhs = self.expression(&children[0])?;
+                let rhs = self.expression(&children[1])?;
+                let operator_type = operator_type(
+                    tree.value(),
+                    lhs.value().symbol_type(),
+                    rhs.value().symbol_type(),
+                    |symbol_type| self.symbol_table.type_name(symbol_type),
+                )
+                .map_err(error)?;
+                node = match tree.value() {
+                    NodeValue::Plus(_) => plus_node(operator_type),
+                    NodeValue::Minus(_) => minus_node(operator_type),
+                    NodeValue::Times(_) => times_node(operator_type),
+                    NodeValue::Divide(_) => divide_node(operator_type),
+                    _ => copy(tree),
+                };
+                node.push_node(lhs);
+                node.push_node(rhs);
+                let span = tree.span().unwrap_or_default();
+                match tree.value() {
+                    NodeValue::Plus(_)
+                    | NodeValue::Minus(_)
+                    | NodeValue::Times(_)
+                    | NodeValue::Divide(_) => {
+                        let constants = self.known_constants(&node);
+                        arithmetic::check_operation(&node, span, &constants).map_err(error)?
+                    }
+                    NodeValue::Eq | NodeValue::Neq => {
+                        self.warnings
+                            .extend(conditions::float_equality(&node, span));
+                    }
+                    _ => {}
+                }
+            }
+            NodeValue::Conditional(_) => node = self.conditional(tree)?,
+            other => return Err(error(format!("unexpected {} in expression", other))),
+        }
+        if let Some(span) = tree.span() {
+            node.set_span(span);
+        }
+        Ok(node)
+    }
+
+    /// Check the access of a member of a struct, e.g., `p.start.x`
+    fn member_access(&mut self, tree: &Tree) -> Result<Tree, Error> {
+        let member = match tree.value() {
+            NodeValue::MemberRef(member, _) => member,
+            _ => unreachable!("only member references are checked as member access"),
+        };
+        let base = &tree.children()[0];
+        let base = match base.value() {
+            NodeValue::MemberRef(_, _) => self.member_access(base)?,
+            _ => {
+                // The accessed struct is a variable or parameter that must have been declared before
+                let name = base.value().symbol_name().unwrap_or_default();
+                match self.symbol_table.get(&name) {
+                    Some(symbol) if !symbol.is_function() => {
+                        variable_ref_node(name, symbol.symbol_type, symbol.id())
+                    }
+                    Some(_) => {
+                        return Err(error(format!("cannot access member of function {}", name)))
+                    }
+                    None => return Err(error(format!("Undeclared symbol {}", name))),
+                }
+            }
+        };
+
+        let base_type = base.value().symbol_type();
+        let definition = match self.symbol_table.struct_definition(base_type) {
+            Some(definition) => definition,
+            None => {
+                return Err(error(format!(
+                    "cannot access member {} of type {}",
+                    member,
+                    self.symbol_table.type_name(base_type)
+                )))
+            }
+        };
+        let member_type = match definition.member_type(member) {
+            Some(member_type) => member_type,
+            None => {
+                return Err(error(format!(
+                    "struct {} has no member {}",
+                    &definition.name, member
+                )))
+            }
+        };
+        let mut node = member_ref_node(member.clone(), member_type);
+        node.push_node(base);
+        Ok(node)
+    }
+
+    /// Check the call of a function, the arguments are checked before the function is looked up
+    fn call(&mut self, name: &str, tree: &Tree) -> Result<Tree, Error> {
+        let mut args = sequence_node();
+        for arguments in tree.children() {
+            for arg in arguments.children() {
+                args.push_node(self.expression(arg)?);
+            }
+        }
+
+        // Recursive calls do not count as calls of the function
+        if self
+            .symbol_table
+            .current_function()
+            .is_none_or(|function| function.name != name)
+        {
+            self.symbol_table.record_read(name);
+        }
+        let symbol = match self.symbol_table.get(name) {
+            Some(symbol) => symbol,
+            None => return Err(error(format!("undeclared symbol {}", name))),
+        };
+        match &symbol.symbol_class {
+            SymbolClass::Function {
+                parameters,
+                defined,
+                builtin,
+            } => {
+                let mut node = if *builtin {
+                    builtin_call_node(name.to_string(), symbol.symbol_type, symbol.id())
+                } else {
+                    function_call_node(name.to_string(), symbol.symbol_type, symbol.id())
+                };
+                if parameters.len() != args.children().len() {
+                    return Err(error(format!(
+                        "{} arguments given, {} expected",
+                        args.children().len(),
+                        parameters.len()
+                    )));
+                }
+                let mismatch = parameters
+                    .iter()
+                    .zip(args.children())
+                    .find(|(parameter, arg)| parameter.symbol_type != arg.value().symbol_type());
+                if let Some((parameter, arg)) = mismatch {
+                    return Err(error(format!(
+                        "incorrect argument type for parameter {} of {}: expected {}, got {}",
+                        parameter.name,
+                        name,
+                        self.symbol_table.symbol_type_name(parameter),
+                        self.symbol_table.type_name(arg.value().symbol_type())
+                    )));
+                }
+                if !defined {
+                    // Only a prototype is known so far, the definition must follow later on
+                    self.undefined_calls.push(name.to_string());
+                }
+                node.push_node(args);
+                Ok(node)
+            }
+            other => Err(error(format!("expected function, got {}", other))),
+        }
+    }
+
+    /// Check the conditional operator `cond ? then : else`, the narrower branch is cast into the type of the other one
+    fn conditional(&mut self, tree: &Tree) -> Result<Tree, Error> {
+        let children = tree.children();
+        let condition = self.expression(&children[0])?;
+        let then = self.expression(&children[1])?;
+        let otherwise = self.expression(&children[2])?;
+
+        let condition_type = condition.value().symbol_type();
+        if condition_type != SymbolType::Boolean {
+            return Err(error(format!(
+                "condition must be boolean, got {}",
+                self.symbol_table.type_name(condition_type)
+            )));
+        }
+        let then_type = then.value().symbol_type();
+        let otherwise_type = otherwise.value().symbol_type();
+        let result_type = if match_types(then_type, otherwise_type) {
+            then_type
+        } else if match_types(otherwise_type, then_type) {
+            otherwise_type
+        } else {
+            return Err(error(format!(
+                "incompatible types {} and {} in conditional expression",
+                self.symbol_table.type_name(then_type),
+                self.symbol_table.type_name(otherwise_type)
+            )));
+        };
+
+        let mut node = conditional_node(result_type);
+        node.push_node(condition);
+        node.push_node(convert(then, result_type));
+        node.push_node(convert(otherwise, result_type));
+        Ok(node)
+    }
+
+    /// Check the assignment of a value to the variable with the given name, the value is converted into the type of
+    /// the variable if necessary
+    fn assign_variable(&mut self, name: &str, value: Tree) -> Result<Tree, Error> {
+        self.symbol_table.record_write(name);
+        let symbol = match self.symbol_table.get(name) {
+            Some(symbol) => symbol,
+            None => return Err(error(format!("undeclared symbol {}", name))),
+        };
+        if symbol.is_function() {
+            return Err(error(format!("cannot assign to function {}", name)));
+        }
+        let value_type = value.value().symbol_type();
+        if !match_types(symbol.symbol_type, value_type) {
+            return Err(error(format!(
+                "cannot assign {} to {}: {} = {}",
+                self.symbol_table.type_name(value_type),
+                self.symbol_table.symbol_type_name(symbol),
+                &symbol.name,
+                value.value()
+            )));
+        }
+        let mut node = assign_node(symbol.symbol_type);
+        node.push_node(variable_ref_node(
+            name.to_string(),
+            symbol.symbol_type,
+            symbol.id(),
+        ));
+        node.push_node(convert(value, symbol.symbol_type));
+        Ok(node)
+    }
+
+    /// Check the assignment of a value to a member of a struct, the value is converted into the type of the member if
+    /// necessary. The members of a constant struct cannot be assigned.
+    fn assign_member(&mut self, target: Tree, value: Tree) -> Result<Tree, Error> {
+        let variable = root_variable(&target);
+        if let Some(symbol) = variable
+            .as_ref()
+            .and_then(|name| self.symbol_table.get(name))
+            .filter(|s| s.is_const)
+        {
+            return Err(error(format!(
+                "cannot assign to member of constant {}",
+                &symbol.name
+            )));
+        }
+        if let Some(name) = variable {
+            self.symbol_table.record_write(&name);
+        }
+
+        let member_type = target.value().symbol_type();
+        let value_type = value.value().symbol_type();
+        if !match_types(member_type, value_type) {
+            return Err(error(format!(
+                "cannot assign {} to {}: {}",
+                self.symbol_table.type_name(value_type),
+                self.symbol_table.type_name(member_type),
+                &target.value()
+            )));
+        }
+        let mut node = assign_node(member_type);
+        node.push_node(target);
+        node.push_node(convert(value, member_type));
+        Ok(node)
+    }
+}
+
+/// Create a semantic error with the given message
+fn error(message: String) -> Error {
+    Error::Semantic(message)
+}
+
+/// Create a node with the value of the root of the given tree, but without its children
+fn copy(tree: &Tree) -> Tree {
+    let mut node = SyntaxTree::new(tree.value().clone());
+    if let Some(span) = tree.span() {
+        node.set_span(span);
+    }
+    node
+}
+
+/// Returns true if the given tree is the initialization of a variable at its declaration
+fn is_initialization(tree: &Tree) -> bool {
+    matches!(tree.value(), NodeValue::Assign(_))
+        && tree.children().first().is_some_and(|child| {
+            matches!(child.value(), NodeValue::VariableDeclaration(_, _, _, _, _))
+        })
+}
+
+/// Return the name of the variable whose member is accessed by the given tree, e.g., `p` for `p.start.x`
+fn root_variable(tree: &Tree) -> Option<String> {
+    let mut variable = tree;
+    while let Some(child) = variable.children().first() {
+        variable = child;
+    }
+    variable.value().symbol_name()
+}
+
+/// Evaluate an integer constant expression, e.g., the value of a `case` label.
+/// Returns None if the expression cannot be evaluated at compile time or its evaluation overflows.
+fn integer_constant(tree: &Tree) -> Option<i32> {
+    constant::evaluate(tree, &HashMap::new())?.integer()
+}
+
+/// Split the format string of a _printf_ statement into text and the placeholders of its arguments. The surrounding
+/// quotes are removed, `%%` is printed as a single `%`.
+fn parse_format_string(format: &str) -> Result<Vec<FormatSegment>, String> {
+    let mut segments = Vec::new();
+    let mut text = String::new();
+    let mut chars = format.trim_matches('"').chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            text.push(c);
+            continue;
+        }
+        let symbol_type = match chars.next() {
+            Some('%') => {
+                text.push('%');
+                continue;
+            }
+            Some('d') => SymbolType::Integer,
+            Some('f') => SymbolType::Float,
+            Some('b') => SymbolType::Boolean,
+            Some('s') => SymbolType::String,
+            Some('c') => SymbolType::Char,
+            Some(other) => {
+                return Err(format!(
+                    "invalid format specifier %{} in format string {}",
+                    other, format
+                ))
+            }
+            None => {
+                return Err(format!(
+                    "incomplete format specifier at the end of format string {}",
+                    format
+                ))
+            }
+        };
+        if !text.is_empty() {
+            segments.push(FormatSegment::Text(std::mem::take(&mut text)));
+        }
+        segments.push(FormatSegment::Argument(symbol_type));
+    }
+    if !text.is_empty() {
+        segments.push(FormatSegment::Text(text));
+    }
+    Ok(segments)
+}
+
+/// Count the placeholders of a string literal that is printed without arguments, e.g., `%d`. Unlike in a format
+/// string, a `%` that starts no placeholder is plain text, e.g., in `"100%"`.
+fn placeholder_count(text: &str) -> usize {
+    let mut count = 0;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            continue;
+        }
+        match chars.peek() {
+            Some('%') => {
+                chars.next();
+            }
+            Some('d' | 'f' | 'b' | 's' | 'c') => {
+                chars.next();
+                count += 1;
+            }
+            _ => {}
+        }
+    }
+    count
+}
+
+/// Return the types of the arguments expected by the placeholders of a format string, in the order of the placeholders
+fn format_arguments(segments: &[FormatSegment]) -> Vec<SymbolType> {
+    segments
+        .iter()
+        .filter_map(|s| match s {
+            FormatSegment::Argument(symbol_type) => Some(*symbol_type),
+            FormatSegment::Text(_) => None,
+        })
+        .collect()
+}
+
+/// Create the error about a format string whose placeholders do not match the number of given arguments
+fn argument_count_error(format: &str, expected: usize, given: usize) -> Error {
+    let arguments = if expected == 1 {
+        "argument"
+    } else {
+        "arguments"
+    };
+    error(format!(
+        "format string {} expected {} {}, got {}",
+        format, expected, arguments, given
+    ))
+}
+
+/// Convert the typed tree of an expression into the given type, which it must be compatible with. A cast node is
+/// inserted if the types differ, e.g., when an integer is assigned to a float variable.
+fn convert(value: Tree, target: SymbolType) -> Tree {
+    if value.value().symbol_type() == target {
+        return value;
+    }
+    let mut node = cast_node(target);
+    node.push_node(value);
+    node
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Lexer, Parser};
+    use std::fs;
+
+    /// Parse the given program without checking it
+    fn parse_syntax(input: &str) -> AnalysisResult {
+        let lexer = Lexer::new(input);
+        let mut parser = Parser::new(lexer);
+        parser.debug = false;
+        parser.do_parse_syntax()
+    }
+
+    /// Return the contents of the test files with the given prefix, together with their names
+    fn test_files(prefix: &str) -> Vec<(String, String)> {
+        let mut files: Vec<(String, String)> = fs::read_dir("tests/testfiles/semantic")
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| {
+                path.file_name()
+                    .unwrap()
+                    .to_string_lossy()
+                    .starts_with(prefix)
+            })
+            .map(|path| {
+                let file_name = path.file_name().unwrap().to_string_lossy().to_string();
+                (file_name, fs::read_to_string(&path).unwrap())
+            })
+            .collect();
+        files.sort();
+        files
+    }
+
+    /// Wrap the given global declarations into a program like the parser does
+    fn program(declarations: Vec<Tree>) -> Tree {
+        let mut sequence = sequence_node();
+        declarations
+            .into_iter()
+            .for_each(|declaration| sequence.push_node(declaration));
+        let mut program = program_node();
+        program.push_node(sequence);
+        let mut root = SyntaxTree::new(NodeValue::Root);
+        root.push_node(program);
+        root
+    }
+
+    /// Create the function `void main()` with the given statements as body
+    fn main_function(statements: Vec<Tree>) -> Tree {
+        let mut main = function_node("main".to_string(), SymbolType::Void, None, 0);
+        main.push_node(sequence_node());
+        let mut body = sequence_node();
+        statements
+            .into_iter()
+            .for_each(|statement| body.push_node(statement));
+        main.push_node(body);
+        main
+    }
+
+    fn node_with(mut node: Tree, children: Vec<Tree>) -> Tree {
+        children.into_iter().for_each(|child| node.push_node(child));
+        node
+    }
+
+    fn variable(name: &str, symbol_type: SymbolType) -> Tree {
+        variable_node(name.to_string(), symbol_type, None, false, 0)
+    }
+
+    fn reference(name: &str) -> Tree {
+        variable_ref_node(name.to_string(), SymbolType::Void, 0)
+    }
+
+    fn expect_error(tree: Tree, message: &str) {
+        match TypeChecker::new().check(&tree) {
+            Err(errors) => assert_eq!(errors[0].to_string(), message),
+            Ok(tree) => panic!("no error reported for {}", tree.print()),
+        }
+    }
+
+    #[test]
+    fn correct_programs() {
+        for (file_name, text) in test_files("test-cor-sem") {
+            let tree = parse_syntax(&text).unwrap();
+            let result = TypeChecker::new().check(&tree);
+            assert!(result.is_ok(), "{}: {:?}", file_name, result.err());
+        }
+    }
+
+    #[test]
+    fn semantic_errors() {
+        for (file_name, text) in test_files("test-err-sem") {
+            // The unknown names of types are already reported by the parser, as it cannot build the tree without them
+            let errors = match parse_syntax(&text) {
+                Ok(tree) => {
+                    let mut checker = TypeChecker::new();
+                    checker.strict_globals = file_name.starts_with("test-err-sem-strict");
+                    checker.check(&tree).err()
+                }
+                Err(errors) => Some(errors),
+            };
+            let errors = errors.unwrap_or_else(|| panic!("{}: no error reported", file_name));
+            assert!(
+                matches!(errors[0], Error::Semantic(_)),
+                "{}: {:?}",
+                file_name,
+                errors
+            );
+        }
+    }
+
+    #[test]
+    fn warnings() {
+        for (file_name, text) in test_files("test-warn-sem") {
+            let tree = parse_syntax(&text).unwrap();
+            let mut checker = TypeChecker::new();
+            checker.lint_shadowing = file_name.starts_with("test-warn-sem-shadowing");
+            let (result, warnings) = checker.check_with_warnings(&tree);
+            assert!(result.is_ok(), "{}: {:?}", file_name, result.err());
+            assert!(!warnings.is_empty(), "{}: no warning reported", file_name);
+        }
+    }
+
+    #[test]
+    fn insert_implicit_cast() {
+        let tree = program(vec![main_function(vec![node_with(
+            assign_node(SymbolType::Void),
+            vec![
+                variable("x", SymbolType::Float),
+                reference("x"),
+                integer_node(1),
+            ],
+        )])]);
+        let checked = TypeChecker::new().check(&tree).unwrap();
+        let assignment =
+            &checked.children()[0].children()[0].children()[0].children()[1].children()[0];
+        assert!(matches!(
+            assignment.value(),
+            NodeValue::Assign(SymbolType::Float)
+        ));
+        assert!(matches!(
+            assignment.children()[2].value(),
+            NodeValue::Cast(SymbolType::Float, false)
+        ));
+    }
+
+    #[test]
+    fn assignment_type() {
+        let tree = program(vec![main_function(vec![node_with(
+            assign_node(SymbolType::Void),
+            vec![
+                variable("x", SymbolType::Integer),
+                reference("x"),
+                boolean_node(true),
+            ],
+        )])]);
+        expect_error(
+            tree,
+            "Semantic Error: cannot assign Boolean to Integer: x = Boolean Literal: true",
+        );
+    }
+
+    #[test]
+    fn alias_in_diagnostics() {
+        let alias = node_with(
+            assign_node(SymbolType::Void),
+            vec![
+                variable_node(
+                    "x".to_string(),
+                    SymbolType::Float,
+                    Some("real".to_string()),
+                    false,
+                    0,
+                ),
+                reference("x"),
+                boolean_node(true),
+            ],
+        );
+        let tree = program(vec![
+            type_alias_node("real".to_string(), SymbolType::Float),
+            main_function(vec![alias]),
+        ]);
+        expect_error(
+            tree,
+            "Semantic Error: cannot assign Boolean to real (aka Float): x = Boolean Literal: true",
+        );
+    }
+
+    #[test]
+    fn undeclared_symbol() {
+        let tree = program(vec![main_function(vec![node_with(
+            assign_node(SymbolType::Void),
+            vec![reference("x"), integer_node(1)],
+        )])]);
+        expect_error(tree, "Semantic Error: undeclared symbol x");
+    }
+
+    #[test]
+    fn condition_type() {
+        let tree = program(vec![main_function(vec![node_with(
+            while_node(),
+            vec![integer_node(1), sequence_node()],
+        )])]);
+        expect_error(tree, "Semantic Error: condition must be boolean");
+    }
+
+    #[test]
+    fn argument_type() {
+        let mut square = function_node("square".to_string(), SymbolType::Integer, None, 0);
+        square.push_node(node_with(
+            sequence_node(),
+            vec![parameter_node(
+                "x".to_string(),
+                SymbolType::Integer,
+                None,
+                0,
+            )],
+        ));
+        square.push_node(node_with(
+            sequence_node(),
+            vec![node_with(
+                return_node(SymbolType::Void),
+                vec![reference("x")],
+            )],
+        ));
+        let call = node_with(
+            function_call_node("square".to_string(), SymbolType::Void, 0),
+            vec![node_with(sequence_node(), vec![float_node(1.5)])],
+        );
+        let tree = program(vec![square, main_function(vec![call])]);
+        expect_error(
+            tree,
+            "Semantic Error: incorrect argument type for parameter x of square: expected Integer, got Float",
+        );
+    }
+
+    #[test]
+    fn strict_global() {
+        let global = node_with(
+            assign_node(SymbolType::Void),
+            vec![
+                variable("x", SymbolType::Integer),
+                reference("x"),
+                node_with(
+                    function_call_node("readInt".to_string(), SymbolType::Void, 0),
+                    vec![sequence_node()],
+                ),
+            ],
+        );
+        let tree = program(vec![global, main_function(vec![])]);
+        assert!(TypeChecker::new().check(&tree).is_ok());
+
+        let mut checker = TypeChecker::new();
+        checker.strict_globals = true;
+        match checker.check(&tree) {
+            Err(errors) => assert_eq!(
+                errors[0].to_string(),
+                "Semantic Error: initializer of global variable x is not a constant expression: \
+                 FunctionCall: readInt (builtin)"
+            ),
+            Ok(tree) => panic!("no error reported for {}", tree.print()),
+        }
+    }
+
+    #[test]
+    fn break_outside_switch() {
+        let tree = program(vec![main_function(vec![break_node()])]);
+        expect_error(tree, "Semantic Error: break statement not within switch");
+    }
+
+    #[test]
+    fn missing_main() {
+        let tree = program(vec![variable("x", SymbolType::Integer)]);
+        expect_error(tree, "Semantic Error: void main() doesn't exist");
+    }
+}
@@ -0,0 +1,119 @@
+use crate::parser::structures::symbol_table::SymbolType;
+use crate::parser::syntax_c1::NodeValue;
+
+/// Determine whether the lhs type can be assigned the rhs type. Return true if lhs is compatible with rhs (i.e., lhs = rhs;)
+pub fn match_types(lhs: SymbolType, rhs: SymbolType) -> bool {
+    lhs == rhs
+        || (lhs == SymbolType::Float && rhs == SymbolType::Integer)
+        || (lhs == SymbolType::Integer && rhs == SymbolType::Char)
+}
+
+/// Determine whether the type is a number. Characters are numbers as well, they are promoted to integers.
+pub fn is_numeric(symbol_type: SymbolType) -> bool {
+    matches!(
+        symbol_type,
+        SymbolType::Integer | SymbolType::Float | SymbolType::Char
+    )
+}
+
+/// Determine whether a value of the source type can be cast explicitly into the target type, e.g., `(int) 2.5`.
+/// Numbers can be converted into each other, a float is truncated when it is cast into an integer. Integers can be
+/// converted into booleans and characters and back as well. Any type can be cast into itself, except for void.
+pub fn explicit_cast_allowed(source: SymbolType, target: SymbolType) -> bool {
+    use SymbolType::*;
+    match (source, target) {
+        (Void, _) | (_, Void) => false,
+        (Integer, Float) | (Float, Integer) | (Integer, Boolean) | (Boolean, Integer) => true,
+        (Integer, Char) | (Char, Integer) => true,
+        (source, target) => source == target,
+    }
+}
+
+/// Determine the type of an operator, e.g., for `1 + 2` the `+`-operator has the result type _integer_. The operand
+/// types are named by `type_name` in errors, e.g., to name struct types after their definitions.
+pub fn operator_type(
+    operator: &NodeValue,
+    lhs: SymbolType,
+    rhs: SymbolType,
+    type_name: impl Fn(SymbolType) -> String,
+) -> Result<SymbolType, String> {
+    match operator {
+        // Only numbers allowed, operator type is integer or float
+        NodeValue::Plus(_) | NodeValue::Minus(_) | NodeValue::Times(_) | NodeValue::Divide(_) => {
+            if let (NodeValue::Plus(_), SymbolType::String, SymbolType::String) =
+                (operator, lhs, rhs)
+            {
+                // Two strings can be concatenated, operator type is string
+                Ok(SymbolType::String)
+            } else if lhs == SymbolType::Boolean || rhs == SymbolType::Boolean {
+                Err(format!(
+                    "Type boolean is not allowed for {} operator",
+                    operator
+                ))
+            } else if lhs == SymbolType::String || rhs == SymbolType::String {
+                Err(format!(
+                    "Type string is not allowed for {} operator",
+                    operator
+                ))
+            } else if let (SymbolType::Struct(_), _) | (_, SymbolType::Struct(_)) = (lhs, rhs) {
+                Err(format!(
+                    "Type struct is not allowed for {} operator",
+                    operator
+                ))
+            } else if lhs == SymbolType::Float || rhs == SymbolType::Float {
+                Ok(SymbolType::Float)
+            } else {
+                // Characters are promoted to integers
+                Ok(SymbolType::Integer)
+            }
+        }
+
+        // Only numbers allowed, operator type is boolean
+        NodeValue::Leq | NodeValue::Geq | NodeValue::Lst | NodeValue::Grt => {
+            if is_numeric(lhs) && is_numeric(rhs) {
+                Ok(SymbolType::Boolean)
+            } else {
+                Err(format!(
+                    "type {} cannot be compared with {} using the {} operator",
+                    type_name(lhs),
+                    type_name(rhs),
+                    operator
+                ))
+            }
+        }
+
+        // Any type allowed, operator type is boolean
+        NodeValue::Eq | NodeValue::Neq => {
+            let comparable = (is_numeric(lhs) && is_numeric(rhs))
+                || (lhs == SymbolType::Boolean && rhs == SymbolType::Boolean)
+                || (lhs == SymbolType::String && rhs == SymbolType::String);
+            if comparable {
+                Ok(SymbolType::Boolean)
+            } else {
+                Err(format!(
+                    "type {} cannot be compared with {} using the {} operator",
+                    type_name(lhs),
+                    type_name(rhs),
+                    operator
+                ))
+            }
+        }
+
+        // Only boolean allowed, operator type is boolean
+        NodeValue::LogOr | NodeValue::LogAnd => {
+            if lhs == SymbolType::Boolean && rhs == SymbolType::Boolean {
+                Ok(SymbolType::Boolean)
+            } else {
+                Err(format!(
+                    "type {} cannot be compared with {} using the {} operator",
+                    type_name(lhs),
+                    type_name(rhs),
+                    operator
+                ))
+            }
+        }
+        _ => {
+            panic!("Invalid usage of combine function. Combine should only be used when the parent node is an operator")
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use crate::parser::syntax_c1::NodeValue;
-use crate::SyntaxTree;
+use crate::{Loc, SyntaxTree};
 use std::fmt::{Display, Formatter};
 
 pub type AnalysisResult = Result<SyntaxTree<NodeValue>, Vec<Error>>;
@@ -26,3 +26,26 @@ impl Display for Error {
         }
     }
 }
+
+/// A finding in the analyzed program that does not prevent its translation, e.g., unreachable code. The span refers
+/// to the range in the source text the warning is about, if it is known. Some warnings also refer to a related range,
+/// e.g., the declaration that is shadowed by the declaration the warning is about.
+#[derive(Debug, Clone)]
+pub struct Warning {
+    pub message: String,
+    pub span: Option<Loc>,
+    pub related: Option<Loc>,
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Warning: {}", self.message)?;
+        if let Some(span) = self.span {
+            write!(f, " at {:?}", span)?;
+        }
+        if let Some(related) = self.related {
+            write!(f, " (see {:?})", related)?;
+        }
+        Ok(())
+    }
+}
@@ -7,12 +7,30 @@ pub enum C1Token {
     #[token("bool")]
     KwBoolean,
 
+    #[token("break")]
+    KwBreak,
+
+    #[token("case")]
+    KwCase,
+
+    #[token("char")]
+    KwChar,
+
+    #[token("const")]
+    KwConst,
+
+    #[token("default")]
+    KwDefault,
+
     #[token("do")]
     KwDo,
 
     #[token("else")]
     KwElse,
 
+    #[token("enum")]
+    KwEnum,
+
     #[token("float")]
     KwFloat,
 
@@ -31,6 +49,18 @@ pub enum C1Token {
     #[token("return")]
     KwReturn,
 
+    #[token("string")]
+    KwString,
+
+    #[token("struct")]
+    KwStruct,
+
+    #[token("switch")]
+    KwSwitch,
+
+    #[token("typedef")]
+    KwTypedef,
+
     #[token("void")]
     KwVoid,
 
@@ -88,6 +118,18 @@ pub enum C1Token {
     #[token(",")]
     Comma,
 
+    #[token(".")]
+    /// .
+    Dot,
+
+    #[token(":")]
+    /// :
+    Colon,
+
+    #[token("?")]
+    /// ?
+    QuestionMark,
+
     #[token(";")]
     Semicolon,
 
@@ -119,7 +161,11 @@ pub enum C1Token {
     #[regex("\"[^\n\"]*\"")]
     ConstString,
 
-    #[regex("[a-zA-Z]+[0-9a-zA-Z]*")]
+    #[regex(r"'([^'\\\n]|\\[nrt0'\\])'")]
+    /// A character literal, e.g. 'a' or the escaped '\n'
+    ConstChar,
+
+    #[regex("[a-zA-Z_][0-9a-zA-Z_]*")]
     Identifier,
 
     #[regex(r"/\*[^\*/]*\*/", logos::skip)]
@@ -154,7 +200,7 @@ pub struct C1Lexer<'a> {
 
 impl<'a> C1Lexer<'a> {
     /// Initialize a new C1Lexer for the given string slice
-    pub fn new(text: &'a str) -> C1Lexer {
+    pub fn new(text: &'a str) -> C1Lexer<'a> {
         let mut lexer = C1Lexer {
             logos_lexer: C1Token::lexer(text),
             logos_line_number: 1,
@@ -182,14 +228,24 @@ impl<'a> C1Lexer<'a> {
             Some(token) => {
                 let token_type: i32 = match token {
                     C1Token::KwBoolean => Self::KW_BOOLEAN,
+                    C1Token::KwBreak => Self::KW_BREAK,
+                    C1Token::KwCase => Self::KW_CASE,
+                    C1Token::KwChar => Self::KW_CHAR,
+                    C1Token::KwConst => Self::KW_CONST,
+                    C1Token::KwDefault => Self::KW_DEFAULT,
                     C1Token::KwDo => Self::KW_DO,
                     C1Token::KwElse => Self::KW_ELSE,
+                    C1Token::KwEnum => Self::KW_ENUM,
                     C1Token::KwFloat => Self::KW_FLOAT,
                     C1Token::KwFor => Self::KW_FOR,
                     C1Token::KwIf => Self::KW_IF,
                     C1Token::KwInt => Self::KW_INT,
                     C1Token::KwPrintf => Self::KW_PRINTF,
                     C1Token::KwReturn => Self::KW_RETURN,
+                    C1Token::KwString => Self::KW_STRING,
+                    C1Token::KwStruct => Self::KW_STRUCT,
+                    C1Token::KwSwitch => Self::KW_SWITCH,
+                    C1Token::KwTypedef => Self::KW_TYPEDEF,
                     C1Token::KwVoid => Self::KW_VOID,
                     C1Token::KwWhile => Self::KW_WHILE,
                     C1Token::Equal => Self::EQ,
@@ -204,6 +260,7 @@ impl<'a> C1Lexer<'a> {
                     C1Token::ConstFloat => Self::CONST_FLOAT,
                     C1Token::ConstBoolean => Self::CONST_BOOLEAN,
                     C1Token::ConstString => Self::CONST_STRING,
+                    C1Token::ConstChar => Self::CONST_CHAR,
                     C1Token::Identifier => Self::ID,
                     C1Token::CComment
                     | C1Token::CPPComment
@@ -216,6 +273,9 @@ impl<'a> C1Lexer<'a> {
                     C1Token::Slash => '/' as i32,
                     C1Token::Assign => '=' as i32,
                     C1Token::Comma => ',' as i32,
+                    C1Token::Dot => '.' as i32,
+                    C1Token::Colon => ':' as i32,
+                    C1Token::QuestionMark => '?' as i32,
                     C1Token::Semicolon => ';' as i32,
                     C1Token::LeftParenthesis => '(' as i32,
                     C1Token::RightParenthesis => ')' as i32,
@@ -430,4 +490,37 @@ mod tests {
         let lexer = C1Lexer::new("33E+2");
         assert_eq!(lexer.current_token(), Some(C1Token::ConstFloat));
     }
+
+    #[test]
+    fn char_recognition() {
+        let lexer = C1Lexer::new("'a'");
+        assert_eq!(lexer.current_token(), Some(C1Token::ConstChar));
+
+        let lexer = C1Lexer::new(r"'\n'");
+        assert_eq!(lexer.current_token(), Some(C1Token::ConstChar));
+
+        let lexer = C1Lexer::new(r"'\''");
+        assert_eq!(lexer.current_token(), Some(C1Token::ConstChar));
+
+        let lexer = C1Lexer::new(r"'\q'");
+        assert_eq!(lexer.current_token(), Some(C1Token::Error));
+
+        let lexer = C1Lexer::new("'ab'");
+        assert_eq!(lexer.current_token(), Some(C1Token::Error));
+    }
+
+    #[test]
+    fn identifier_recognition() {
+        let lexer = C1Lexer::new("counter2");
+        assert_eq!(lexer.current_token(), Some(C1Token::Identifier));
+
+        let lexer = C1Lexer::new("_unused");
+        assert_eq!(lexer.current_token(), Some(C1Token::Identifier));
+
+        let lexer = C1Lexer::new("max_value");
+        assert_eq!(lexer.current_token(), Some(C1Token::Identifier));
+
+        let lexer = C1Lexer::new("2nd");
+        assert_eq!(lexer.current_token(), Some(C1Token::ConstInt));
+    }
 }
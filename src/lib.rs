@@ -1,9 +1,15 @@
+mod analysis;
+mod checker;
 mod error;
 mod lexer;
 mod parser;
 
+pub use analysis::call_graph::CallGraph;
+pub use analysis::reachability::{unreachable_code_in_program, UnreachableCode};
+pub use checker::TypeChecker;
 pub use error::AnalysisResult;
 pub use error::Error;
+pub use error::Warning;
 pub use lexer::{C1Lexer as Lexer, C1Token as Token};
 pub use parser::bison_skeleton::loc::Loc;
 pub use parser::minako_syntax::Parser;
@@ -98,3 +104,49 @@ Process finished with exit code 0
     );
     assert!(errors.is_ok());
 }
+
+#[test]
+fn resolve_shadowed_names() {
+    use parser::syntax_c1::NodeValue;
+
+    // Collect the declarations and references of `a` with their symbol ids in preorder
+    fn occurrences(tree: &SyntaxTree<NodeValue>, result: &mut Vec<(bool, usize)>) {
+        match tree.value() {
+            NodeValue::VariableDeclaration(name, _, _, _, id) if name == "a" => {
+                result.push((true, *id))
+            }
+            NodeValue::VariableRef(name, _, id) if name == "a" => result.push((false, *id)),
+            _ => {}
+        }
+        tree.children()
+            .iter()
+            .for_each(|child| occurrences(child, result));
+    }
+
+    let tree = parse(
+        r"void main() {
+    int a = 1;
+    {
+        int a = 2;
+        printf(a);
+    }
+    printf(a);
+}",
+    )
+    .unwrap();
+    let mut result = Vec::new();
+    occurrences(&tree, &mut result);
+    let (outer, inner) = (result[0].1, result[2].1);
+    assert_ne!(outer, inner);
+    assert_eq!(
+        result,
+        vec![
+            (true, outer),
+            (false, outer),
+            (true, inner),
+            (false, inner),
+            (false, inner),
+            (false, outer)
+        ]
+    );
+}
@@ -1,6 +1,6 @@
 use logos::Span;
 
-///
+/// A range of characters in the source text
 #[derive(Clone, PartialEq, Eq, Default, Copy)]
 #[repr(C)]
 pub struct Loc {
@@ -9,13 +9,14 @@ use std::fmt::{Display, Formatter};
 ///
 /// This values has to be in a single enum, because LALR parsers
 /// have a stack, and it's better for it to be heterogeneous.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub enum Value {
     /// Required variant, parser expects it to be defined
     None,
     /// Required variant, parser expects it to be defined
     Uninitialized,
     /// Required variant, parser expects it to be defined
+    #[default]
     Stolen,
 
     /// Required variant, parser expects it to be defined.
@@ -28,14 +29,8 @@ pub enum Value {
     /// Variant for wrapping SymbolTypes so that symbols can be passed between bison rules
     SymbolType(SymbolType),
 
-    /// Variant for wrapping symbol names so that they can be passed between bison rules
-    Name(String),
-}
-
-impl Default for Value {
-    fn default() -> Self {
-        Self::Stolen
-    }
+    /// Variant for wrapping type aliases with their name and underlying SymbolType, e.g. `real` for `float`
+    TypeAlias(String, SymbolType),
 }
 
 impl Value {
@@ -76,23 +71,30 @@ impl Value {
         }
     }
 
-    /// Return the String wrapped by this Value. This method panics if the Value instance is not
-    /// a Name or Token variant.
+    /// Return the text of the Token wrapped by this Value, e.g., the name of a symbol. This method
+    /// panics if the Value instance is not a Token variant.
     pub fn unwrap_name(self) -> String {
         match self {
             Value::Token(token) => token.text,
-            Value::Name(name) => name,
             _ => panic!("Expected symbol name, found {}", self),
         }
     }
 
-    /// Return the SymbolType wrapped by this Value. This method panics if the Value instance is not
-    /// a SymbolType variant.
+    /// Return the SymbolType wrapped by this Value, type aliases are resolved to their underlying type.
+    /// This method panics if the Value instance is not a SymbolType or TypeAlias variant.
     pub fn unwrap_type(self) -> SymbolType {
-        if let Value::SymbolType(t) = self {
-            t
-        } else {
-            panic!("Expected symbol type, found {}", self);
+        match self {
+            Value::SymbolType(t) | Value::TypeAlias(_, t) => t,
+            _ => panic!("Expected symbol type, found {}", self),
+        }
+    }
+
+    /// Return the name of the type alias wrapped by this Value, or None if the Value is not a
+    /// TypeAlias variant.
+    pub fn type_alias(&self) -> Option<String> {
+        match self {
+            Value::TypeAlias(alias, _) => Some(alias.clone()),
+            _ => None,
         }
     }
 }
@@ -0,0 +1,197 @@
+use crate::parser::structures::symbol_table::SymbolType;
+use crate::parser::syntax_c1::*;
+use crate::SyntaxTree;
+use std::collections::HashMap;
+
+/// Represents the value of an expression that has been computed at compile time
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConstantValue {
+    Integer(i32),
+    Float(f64),
+    Boolean(bool),
+    // The String holds the literal including its quotes, like the string node
+    String(String),
+    Char(char),
+}
+
+impl ConstantValue {
+    /// Create a literal node holding this value
+    pub fn node(&self) -> SyntaxTree<NodeValue> {
+        match self {
+            ConstantValue::Integer(v) => integer_node(*v),
+            ConstantValue::Float(v) => float_node(*v),
+            ConstantValue::Boolean(v) => boolean_node(*v),
+            ConstantValue::String(v) => string_node(v.clone()),
+            ConstantValue::Char(v) => char_node(*v),
+        }
+    }
+
+    /// Return the value as an integer, characters are promoted to their code. Returns None for any other value.
+    pub fn integer(&self) -> Option<i32> {
+        match self {
+            ConstantValue::Integer(v) => Some(*v),
+            ConstantValue::Char(v) => Some(*v as i32),
+            _ => None,
+        }
+    }
+
+    /// Return the value as a float, integers and characters are converted. Returns None for any other value.
+    fn float(&self) -> Option<f64> {
+        match self {
+            ConstantValue::Float(v) => Some(*v),
+            _ => self.integer().map(f64::from),
+        }
+    }
+
+    /// Convert the value into the given type like a cast does. Returns None if the conversion is not possible.
+    fn cast(&self, target: SymbolType) -> Option<ConstantValue> {
+        match (self, target) {
+            // The conversion fails if the integral part of the value is not an Integer
+            (ConstantValue::Float(v), SymbolType::Integer) => {
+                let integral = v.trunc();
+                let in_range = integral >= f64::from(i32::MIN) && integral <= f64::from(i32::MAX);
+                in_range.then_some(ConstantValue::Integer(integral as i32))
+            }
+            (ConstantValue::Boolean(v), SymbolType::Integer) => {
+                Some(ConstantValue::Integer(*v as i32))
+            }
+            (_, SymbolType::Integer) => self.integer().map(ConstantValue::Integer),
+            (_, SymbolType::Float) => self.float().map(ConstantValue::Float),
+            (ConstantValue::Integer(v), SymbolType::Boolean) => {
+                Some(ConstantValue::Boolean(*v != 0))
+            }
+            (ConstantValue::Boolean(_), SymbolType::Boolean) => Some(self.clone()),
+            (ConstantValue::Integer(v), SymbolType::Char) => u32::try_from(*v)
+                .ok()
+                .and_then(char::from_u32)
+                .map(ConstantValue::Char),
+            (ConstantValue::Char(_), SymbolType::Char)
+            | (ConstantValue::String(_), SymbolType::String) => Some(self.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// Compute the value of an expression at compile time. Variables are constant if their value is given in `constants`,
+/// a reference to any other variable or a function call makes the expression non-constant. Returns None if the
+/// expression is not constant or its evaluation fails, e.g., on an integer overflow or a division by zero.
+pub fn evaluate(
+    tree: &SyntaxTree<NodeValue>,
+    constants: &HashMap<String, ConstantValue>,
+) -> Option<ConstantValue> {
+    let operand = |index: usize| {
+        tree.children()
+            .get(index)
+            .and_then(|child| evaluate(child, constants))
+    };
+    match tree.value() {
+        NodeValue::Integer(v) | NodeValue::ConstantRef(_, v, _) => Some(ConstantValue::Integer(*v)),
+        NodeValue::Float(v) => Some(ConstantValue::Float(*v)),
+        NodeValue::Boolean(v) => Some(ConstantValue::Boolean(*v)),
+        NodeValue::String(v) => Some(ConstantValue::String(v.clone())),
+        NodeValue::Char(v) => Some(ConstantValue::Char(*v)),
+        NodeValue::VariableRef(name, _, _) => constants.get(name).cloned(),
+        NodeValue::Cast(target, _) => operand(0)?.cast(*target),
+        NodeValue::UMinus(SymbolType::Float) => Some(ConstantValue::Float(-operand(0)?.float()?)),
+        NodeValue::UMinus(_) => operand(0)?
+            .integer()?
+            .checked_neg()
+            .map(ConstantValue::Integer),
+        NodeValue::Plus(_) | NodeValue::Minus(_) | NodeValue::Times(_) | NodeValue::Divide(_) => {
+            arithmetic(tree.value(), operand(0)?, operand(1)?)
+        }
+        NodeValue::Eq
+        | NodeValue::Neq
+        | NodeValue::Leq
+        | NodeValue::Geq
+        | NodeValue::Lst
+        | NodeValue::Grt => {
+            comparison(tree.value(), operand(0)?, operand(1)?).map(ConstantValue::Boolean)
+        }
+        NodeValue::LogOr | NodeValue::LogAnd => match (operand(0)?, operand(1)?) {
+            (ConstantValue::Boolean(lhs), ConstantValue::Boolean(rhs)) => {
+                let value = if let NodeValue::LogOr = tree.value() {
+                    lhs || rhs
+                } else {
+                    lhs && rhs
+                };
+                Some(ConstantValue::Boolean(value))
+            }
+            _ => None,
+        },
+        NodeValue::Conditional(_) => match operand(0)? {
+            ConstantValue::Boolean(true) => operand(1),
+            ConstantValue::Boolean(false) => operand(2),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Apply an arithmetic operator to two constant operands. The operands are promoted to the operator type.
+fn arithmetic(
+    operator: &NodeValue,
+    lhs: ConstantValue,
+    rhs: ConstantValue,
+) -> Option<ConstantValue> {
+    match (operator, lhs, rhs) {
+        (
+            NodeValue::Plus(SymbolType::String),
+            ConstantValue::String(lhs),
+            ConstantValue::String(rhs),
+        ) => {
+            // Both literals are joined without the quotes between them
+            Some(ConstantValue::String(format!(
+                "{}{}",
+                &lhs[..lhs.len() - 1],
+                &rhs[1..]
+            )))
+        }
+        (NodeValue::Plus(SymbolType::Float), lhs, rhs) => {
+            Some(ConstantValue::Float(lhs.float()? + rhs.float()?))
+        }
+        (NodeValue::Minus(SymbolType::Float), lhs, rhs) => {
+            Some(ConstantValue::Float(lhs.float()? - rhs.float()?))
+        }
+        (NodeValue::Times(SymbolType::Float), lhs, rhs) => {
+            Some(ConstantValue::Float(lhs.float()? * rhs.float()?))
+        }
+        (NodeValue::Divide(SymbolType::Float), lhs, rhs) => {
+            Some(ConstantValue::Float(lhs.float()? / rhs.float()?))
+        }
+        (operator, lhs, rhs) => {
+            let (lhs, rhs) = (lhs.integer()?, rhs.integer()?);
+            let value = match operator {
+                NodeValue::Plus(_) => lhs.checked_add(rhs),
+                NodeValue::Minus(_) => lhs.checked_sub(rhs),
+                NodeValue::Times(_) => lhs.checked_mul(rhs),
+                _ => lhs.checked_div(rhs),
+            };
+            value.map(ConstantValue::Integer)
+        }
+    }
+}
+
+/// Apply a comparison operator to two constant operands. Numbers are compared by their value, booleans and strings
+/// can only be compared for (in)equality.
+fn comparison(operator: &NodeValue, lhs: ConstantValue, rhs: ConstantValue) -> Option<bool> {
+    let ordering = match (&lhs, &rhs) {
+        (ConstantValue::Boolean(_), _) | (ConstantValue::String(_), _) => {
+            let equal = lhs == rhs;
+            return match operator {
+                NodeValue::Eq => Some(equal),
+                NodeValue::Neq => Some(!equal),
+                _ => None,
+            };
+        }
+        _ => lhs.float()?.partial_cmp(&rhs.float()?)?,
+    };
+    match operator {
+        NodeValue::Eq => Some(ordering.is_eq()),
+        NodeValue::Neq => Some(ordering.is_ne()),
+        NodeValue::Leq => Some(ordering.is_le()),
+        NodeValue::Geq => Some(ordering.is_ge()),
+        NodeValue::Lst => Some(ordering.is_lt()),
+        _ => Some(ordering.is_gt()),
+    }
+}
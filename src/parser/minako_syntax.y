@@ -10,18 +10,35 @@
 
 %code use {
     // all use goes here
-    use crate::{Lexer, AnalysisResult, Error};
-    use super::bison_skeleton::{token::Token, value::Value::{self, Tree, Name}, loc::Loc};
-    use super::structures::{symbol_table::{SymbolTable, SymbolClass, SymbolType}, syntax_tree::SyntaxTree};
+    use crate::{Lexer, AnalysisResult, Error, TypeChecker, Warning};
+    use super::bison_skeleton::{token::Token, value::Value::{self, Tree}, loc::Loc};
+    use super::structures::{symbol_table::SymbolType, syntax_tree::SyntaxTree};
     use super::syntax_c1::*;
+    use std::collections::HashMap;
 }
 
 %code parser_fields {
     errors: Vec<Error>,
-    symbol_table: SymbolTable,
     syntax_tree: SyntaxTree<NodeValue>,
+    // The names that are declared in each of the nested scopes, a type alias holds the type it stands for. The lexer
+    // cannot distinguish type aliases from other identifiers, hence they are resolved by the parser.
+    names: Vec<HashMap<String, Option<SymbolType>>>,
+    // The type of the token that has been passed to the parser last, and a token that has been read ahead of time
+    previous_token_type: i32,
+    peeked_token: Option<Token>,
+    // The names of the struct types that have been defined so far, a struct type refers to its definition by index
+    struct_names: Vec<String>,
+    // The type, its alias, and the qualifier of the declaration that is currently parsed, they apply to all of its
+    // declarators
+    declaration_type: SymbolType,
+    declaration_alias: Option<String>,
+    declaration_const: bool,
     /// Enables debug printing
     pub debug: bool,
+    /// Requires the initializers of global variables to be constant expressions, which are evaluated at compile time
+    pub strict_globals: bool,
+    /// Warns about declarations of variables and parameters that shadow a declaration of an outer scope
+    pub lint_shadowing: bool,
 }
 
 %token
@@ -34,21 +51,33 @@
     LSS           "<"
     GRT           ">"
     KW_BOOLEAN    "bool"
+    KW_BREAK      "break"
+    KW_CASE       "case"
+    KW_CHAR       "char"
+    KW_CONST      "const"
+    KW_DEFAULT    "default"
     KW_DO         "do"
     KW_ELSE       "else"
+    KW_ENUM       "enum"
     KW_FLOAT      "float"
     KW_FOR        "for"
     KW_IF         "if"
     KW_INT        "int"
     KW_PRINTF     "printf"
     KW_RETURN     "return"
+    KW_STRING     "string"
+    KW_STRUCT     "struct"
+    KW_SWITCH     "switch"
+    KW_TYPEDEF    "typedef"
     KW_VOID       "void"
     KW_WHILE      "while"
     CONST_INT     "integer literal"
     CONST_FLOAT   "float literal"
     CONST_BOOLEAN "boolean literal"
     CONST_STRING  "string literal"
+    CONST_CHAR    "character literal"
     ID            "identifier"
+    TYPE_NAME     "type name"
 
 // definition of association and precedence of operators
 %left '+' '-' OR
@@ -64,42 +93,12 @@
 
 start:
 	program {
-		match self.symbol_table.get("main") {
-			// The `start` rule has a different return type than the other rules, this is why this weird `unwrap_err()` construct is needed here.
-                            None => {return Err(self.report_semantic_error("void main() doesn't exist").unwrap_err())}
-                            Some(symbol) => {
-                                if let SymbolClass::Function {parameters} = &symbol.symbol_class {
-                                    match &symbol.symbol_type {
-                                        SymbolType::Void => {
-                                        }
-                                        _ => {
-                                            return Err(self.report_semantic_error("main() must be of type void").unwrap_err())
-                                        }
-                                    }
-                                    if !parameters.is_empty() {
-                                        return Err(self.report_semantic_error("void main() cannot have parameters").unwrap_err())
-                                    }
-                            		// TODO: Handle remaining main related semantic errors
-                            	        // The `start` rule has a different return type than the other rules.
-                            	        // Therefore the returned error has to be rewrapped with
-                            	        // return Err(self.report_semantic_error("ERROR").unwrap_err())
-                                } else {
-                                    return Err(self.report_semantic_error("void main() doesn't exist").unwrap_err())
-                                    // TODO: Handle remaining main related semantic errors
-                                }
-
-                                let mut program_node = program_node();
-                                // Push the already parsed tree that is now on top of the stack
-                                if let Tree(tree) = $program {
-                                    program_node.push_node(tree);
-                                } else {
-                                    ast_parse_error();
-                                }
-                                // Add the parsed AST to the virtual root
-                                self.syntax_tree.push_node(program_node);
-                        }};
-
-                $$ = Value::None;
+		// The semantics of the program are checked by the type checker once the whole tree has been built
+		let mut program_node = program_node();
+		program_node.push_node($program.unwrap_tree());
+		// Add the parsed AST to the virtual root
+		self.syntax_tree.push_node(program_node);
+		$$ = Value::None;
 	}
 	;
 
@@ -123,36 +122,163 @@ program:
 		parent.push_node(child);
 		$$ = Tree(parent);
 	}
-functiondefinition:
-	type ID[name] {
+	| program[prog] functionprototype[proto] ';'
+	{
+		let mut parent = $prog.unwrap_tree();
+		let child = $proto.unwrap_tree();
+		parent.push_node(child);
+		$$ = Tree(parent);
+	}
+	| program[prog] structdefinition[def] ';'
+	{
+		let mut parent = $prog.unwrap_tree();
+		let child = $def.unwrap_tree();
+		parent.push_node(child);
+		$$ = Tree(parent);
+	}
+	| program[prog] enumdefinition[def] ';'
+	{
+		let mut parent = $prog.unwrap_tree();
+		let child = $def.unwrap_tree();
+		parent.push_node(child);
+		$$ = Tree(parent);
+	}
+	| program[prog] typedefinition[def]
+	{
+		let mut parent = $prog.unwrap_tree();
+		let child = $def.unwrap_tree();
+		parent.push_node(child);
+		$$ = Tree(parent);
+	}
+
+typedefinition:
+	KW_TYPEDEF type ID[name] {
+		// The alias is known before the ';' is shifted, so that the lexer already knows it for the next token
 		let name = $name.unwrap_name();
-	    let symbol_type = $type.unwrap_type();
-		let symbol = self.symbol_table.function_symbol(name.clone(), symbol_type);
-		match self.symbol_table.insert(symbol) {
-		    Ok(_) => {
-		        self.symbol_table.enter_scope();
-		        }
-		    Err(_) => {
-				return self.report_semantic_error("function already defined");
-    			}
+		let symbol_type = $type.unwrap_type();
+		self.bind_name(name.clone(), Some(symbol_type));
+		$$ = Tree(type_alias_node(name, symbol_type));
+	} ';' {
+		$$ = $4;
+	}
+	| KW_TYPEDEF type TYPE_NAME[name] ';' {
+		// The name is already known as a type alias, the type checker reports the redefinition
+		$$ = Tree(type_alias_node($name.unwrap_name(), $type.unwrap_type()));
+	}
+
+structdefinition:
+	KW_STRUCT ID[name] '{' memberlist[members] '}' {
+		// The struct types are numbered in the order of their definitions, like in the symbol table
+		let name = $name.unwrap_name();
+		if !self.struct_names.contains(&name) {
+		    self.struct_names.push(name.clone());
 		}
-                // TODO: Use symbol table
 
-                $$ = Value::Tree(function_node(name));
+		let mut definition_node = struct_definition_node(name);
+		for member in $members.unwrap_tree().children() {
+		    definition_node.push_node(member.clone());
+		}
+		$$ = Tree(definition_node);
 	}
-	'(' opt_parameterlist[params] ')' '{' statementlist[body] '}' {
-	    let mut function_node = $3.unwrap_tree();
-	    if let Tree(params) = $params {
-	        function_node.push_node(params);
-	    }
-	    let body = $body.unwrap_tree();
-	    function_node.push_node(body);
 
+enumdefinition:
+	KW_ENUM ID[name] '{' enumeratorlist[enumerators] '}' {
+		let mut definition_node = enum_definition_node($name.unwrap_name());
+		for enumerator in $enumerators.unwrap_tree().children() {
+		    definition_node.push_node(enumerator.clone());
+		}
+		$$ = Tree(definition_node);
+	}
 
-        self.symbol_table.leave_scope();
+enumeratorlist:
+	enumerator
+		{
+		let mut sequence_node = sequence_node();
+		sequence_node.push_node($enumerator.unwrap_tree());
+		$$ = Tree(sequence_node);
+		}
+	| enumeratorlist[list] ',' enumerator
+		{
+		let mut list_node = $list.unwrap_tree();
+		list_node.push_node($enumerator.unwrap_tree());
+		$$ = Tree(list_node);
+		}
 
-            // TODO: Use symbol table
+enumerator:
+	ID[name] {
+		// The enumerators are numbered by the type checker, unless a value is given explicitly
+		$$ = Tree(enumerator_node($name.unwrap_name(), 0, 0));
+	}
+	| ID[name] '=' simpexpr[value] {
+		let mut enumerator_node = enumerator_node($name.unwrap_name(), 0, 0);
+		enumerator_node.push_node($value.unwrap_tree());
+		$$ = Tree(enumerator_node);
+	}
 
+memberlist:
+	member
+		{
+		let mut sequence_node = sequence_node();
+		sequence_node.push_node($member.unwrap_tree());
+		$$ = Tree(sequence_node);
+		}
+	| memberlist[list] member
+		{
+		let mut list_node = $list.unwrap_tree();
+		list_node.push_node($member.unwrap_tree());
+		$$ = Tree(list_node);
+		}
+
+member:
+	type ID[name] ';' {
+		$$ = Tree(member_node($name.unwrap_name(), $type.unwrap_type()));
+	}
+
+functiondefinition:
+	functionheader[header] '{' statementlist[body] {
+	    // The scope is left before the '}' is shifted, so that the token after it is read in the outer scope
+	    self.leave_scope();
+	    $$ = Value::None;
+	} '}' {
+	    let mut function_node = $header.unwrap_tree();
+	    function_node.push_node($body.unwrap_tree());
+	    $$ = Tree(function_node);
+	}
+
+functionprototype:
+	functionheader[header] {
+	    // A prototype declares the function without defining it, e.g. for mutually recursive functions
+	    self.leave_scope();
+	    let header = $header.unwrap_tree();
+	    let mut prototype_node = match header.value() {
+	        NodeValue::FunctionDeclaration(name, symbol_type, alias, id) => {
+	            function_prototype_node(name.clone(), *symbol_type, alias.clone(), *id)
+	        }
+	        _ => unreachable!("a function header is a function declaration"),
+	    };
+	    if let Some(span) = header.span() {
+	        prototype_node.set_span(span);
+	    }
+	    for child in header.children() {
+	        prototype_node.push_node(child.clone());
+	    }
+	    $$ = Tree(prototype_node);
+	}
+
+functionheader:
+	type ID[name] '(' {
+	    // The parameters and the body of the function share a scope
+	    self.enter_scope();
+	    $$ = Value::None;
+	} opt_parameterlist[params] ')' {
+	    let declared_type = $type;
+	    let type_alias = declared_type.type_alias();
+	    let mut function_node = function_node($name.unwrap_name(), declared_type.unwrap_type(), type_alias, 0);
+	    // The span refers to the name of the function, like the spans of other declarations
+	    function_node.set_span(*@name);
+	    if let Tree(params) = $params {
+	        function_node.push_node(params);
+	    }
 	    $$ = Tree(function_node);
 	}
 
@@ -182,74 +308,23 @@ parameterlist:
 	}
 
 parameter:
-	type ID[name] {
-		let name =$name.unwrap_name();
-		let symbol_type = $type.unwrap_type();
-		match symbol_type {
-		    SymbolType::Void => {
-		        return self.report_semantic_error("parameter cannot be of type void");
-		    }
-		    _ => {
-
-		    }
-		}
-        let symbol = self.symbol_table.parameter_symbol(name.clone(), symbol_type);
-        match self.symbol_table.insert(symbol) {
-            Ok(_) => {
-                self.symbol_table.parameter_symbol(name.clone(), symbol_type);
-                }
-            Err(_) => {
-                return self.report_semantic_error("incorrect parameter");
-                }
-        }
-
-		// TODO: Check parameter type
-	        // TODO: Use symbol table
-	        $$ = Tree(parameter_node(name));
+	type identifier[name] {
+		let declared_type = $type;
+		let type_alias = declared_type.type_alias();
+		let name = $name.unwrap_name();
+		self.bind_name(name.clone(), None);
+		let mut parameter_node = parameter_node(name, declared_type.unwrap_type(), type_alias, 0);
+		parameter_node.set_span(*@name);
+		$$ = Tree(parameter_node);
 	}
 
 functioncall:
 	ID[name] '(' opt_argumentlist[args] ')' {
-		// The variable must already be declared
-		let name =$name.unwrap_name();
-		match self.symbol_table.get(&name) {
-			None => {
-				let error = format!("undeclared symbol {}", &name);
-				return self.report_semantic_error(&error);
-			}
-			Some(symbol) => {
-				// It has been declared, but is it really a function?
-				match &symbol.symbol_class {
-				SymbolClass::Function {parameters} => {
-					let mut call_node = function_call_node(name.clone(), symbol.symbol_type);
-					let arg_node = $args.unwrap_tree();
-					let arg_types: Vec<SymbolType> = arg_node.children().iter().map(|c| c.value().symbol_type()).collect();
-                    if parameters.len() != arg_types.len() {
-                        let error = format!("{} arguments given, {} expected", arg_types.len(), parameters.len());
-                        return self.report_semantic_error(&error);
-                    }
-                    else {
-                        let mut arg_iter = arg_types.clone().into_iter();
-                        for param in parameters {
-                            if param.symbol_type != arg_iter.next().unwrap() {
-                                return self.report_semantic_error("incorrect argument type");
-                            }
-                        }
-                    }
-					// TODO: Compare parameters with arguments
-
-					call_node.push_node(arg_node);
-					$$ = Tree(call_node);
-				}
-				_ => {
-				    let error = format!("expected function, got {}", &symbol.symbol_class);
-				    return self.report_semantic_error(&error);
-					// TODO: Handle symbol being not a function
-				}
-			}
-		}
+		// The called function is resolved by the type checker, which also tells builtin functions apart
+		let mut call_node = function_call_node($name.unwrap_name(), SymbolType::Void, 0);
+		call_node.push_node($args.unwrap_tree());
+		$$ = Tree(call_node);
 	}
-}
 
 opt_argumentlist:
 	/* empty */
@@ -283,32 +358,29 @@ statementlist:
 	| statementlist[list] statement[elem]
 		{
 		let mut list_node = $list.unwrap_tree();
-		list_node.push_node($elem.unwrap_tree());
+		list_node.push_node(statement_with_span($elem.unwrap_tree(), *@elem));
 		$$ = Tree(list_node);
 		}
 
 block:
 	'{' {
-	self.symbol_table.enter_scope();
-	// TODO: Use symbol_table
+	self.enter_scope();
 	$$ = Value::None;
-	}
-		statementlist[body]
-	'}' {
-	self.symbol_table.leave_scope();
-	// TODO: Use symbol_table
+	} statementlist[body] {
+	// The scope is left before the '}' is shifted, so that the token after it is read in the outer scope
+	self.leave_scope();
+	$$ = Value::None;
+	} '}' {
  	$$ = $body;
 	}
 
 body:
 	{
-	    self.symbol_table.enter_scope();
-		// TODO: Use symbol_table
+		self.enter_scope();
 		$$ = Value::None;
 	} statement {
-	    self.symbol_table.leave_scope();
-		// TODO: Use symbol_table
-		$$ = $statement;
+		self.leave_scope();
+		$$ = Tree(statement_with_span($statement.unwrap_tree(), *@statement));
 	}
 
 statement:
@@ -324,6 +396,14 @@ statement:
 	  {
 	  $$ = $whilestatement;
 	  }
+	| switchstatement
+	  {
+	  $$ = $switchstatement;
+	  }
+	| KW_BREAK ';'
+	  {
+	  $$ = Tree(break_node());
+	  }
 	| returnstatement ';'
 	  {
 	  $$ = $returnstatement;
@@ -340,6 +420,10 @@ statement:
 	  {
 	  $$ = $declassignment;
 	  }
+	| enumdefinition ';'
+	  {
+	  $$ = $enumdefinition;
+	  }
 	| statassignment ';'
 	  {
 	  $$ = $statassignment;
@@ -355,16 +439,8 @@ statement:
 
 ifstatement:
 	KW_IF '(' assignment[cond] ')' body[then] opt_else[else] {
-	let condition_node = $cond.unwrap_tree();
-	match condition_node.value().symbol_type() {
-        SymbolType::Boolean => {
-
-        }
-        _ => {
-            return self.report_semantic_error("condition must be boolean");
-        }
-	}
-	// TODO: Verify condition's type
+	let mut condition_node = $cond.unwrap_tree();
+	condition_node.set_span(*@cond);
 
 	let mut if_node = if_node();
 	if_node.push_node(condition_node);
@@ -390,207 +466,178 @@ opt_else:
 
 forstatement:
 	KW_FOR '(' {
-	self.symbol_table.enter_scope();
+	self.enter_scope();
 	$$ = Value::None;
-	} declassignment[init] ';' expr[cond] ';' statassignment[step] ')' body {
+	} forinit[init] ';' expr[cond] ';' statassignment[step] ')' body {
+	self.leave_scope();
 	let mut for_node = for_node();
-	let init_node = $init.unwrap_tree();
-	let cond_node = $cond.unwrap_tree();
-	let step_node = $step.unwrap_tree();
-	let body_node = $body.unwrap_tree();
-
+	let mut cond_node = $cond.unwrap_tree();
+	cond_node.set_span(*@cond);
 
-	match cond_node.value().symbol_type() {
-        SymbolType::Boolean => {
-
-        }
-        _ => {
-            return self.report_semantic_error("condition must be boolean");
-        }
-	}
-	// TODO: Verify condition type
-
-	for_node.push_node(init_node);
+	for_node.push_node($init.unwrap_tree());
 	for_node.push_node(cond_node);
-	for_node.push_node(step_node);
-	for_node.push_node(body_node);
-
+	for_node.push_node($step.unwrap_tree());
+	for_node.push_node($body.unwrap_tree());
 	$$ = Tree(for_node);
-	self.symbol_table.leave_scope();
 	}
-	| KW_FOR '('
-	{
-		// This scope only belongs to the for statements head + body, no symbols will be defined, as
-		// an already declared variable is used in this for variant
-		self.symbol_table.enter_scope();
-		$$ = Value::None;
-	}
-	statassignment[init] ';' expr[cond] ';' statassignment[step] ')' body {
-	let mut for_node = for_node();
-	let init_node = $init.unwrap_tree();
-	let cond_node = $cond.unwrap_tree();
-	let step_node = $step.unwrap_tree();
-	let body_node = $body.unwrap_tree();
 
-	match cond_node.value().symbol_type() {
-        SymbolType::Boolean => {
-
-        }
-        _ => {
-            return self.report_semantic_error("condition must be boolean");
-        }
+forinit:
+	declassignment {
+	$$ = $declassignment;
 	}
-	// TODO: Verify condition type
-
-	for_node.push_node(init_node);
-	for_node.push_node(cond_node);
-	for_node.push_node(step_node);
-	for_node.push_node(body_node);
-
-	$$ = Tree(for_node);
-	self.symbol_table.leave_scope();
+	| statassignment {
+	// This for variant uses an already declared variable
+	$$ = $statassignment;
 	}
 
 dowhilestatement:
 	KW_DO body KW_WHILE '(' assignment[cond] ')' {
-	let condition_node = $cond.unwrap_tree();
-
-	match condition_node.value().symbol_type() {
-        SymbolType::Boolean => {
-
-        }
-        _ => {
-            return self.report_semantic_error("condition must be boolean");
-        }
-	}
-	// TODO: Verify condition type
+	let mut condition_node = $cond.unwrap_tree();
+	condition_node.set_span(*@cond);
 	$$ = combine(do_while_node(), Tree(condition_node), $body);
 	}
 
 whilestatement:
 	KW_WHILE '(' assignment[cond] ')' body {
-	let condition_node = $cond.unwrap_tree();
-
-	match condition_node.value().symbol_type() {
-        SymbolType::Boolean => {
+	let mut condition_node = $cond.unwrap_tree();
+	condition_node.set_span(*@cond);
+	$$ = combine(while_node(), Tree(condition_node), $body);
+	}
 
-        }
-        _ => {
-            return self.report_semantic_error("condition must be boolean");
-        }
+switchstatement:
+	KW_SWITCH '(' assignment[scrutinee] ')' '{' {
+	// The body of a switch is a single scope shared by all of its labels
+	self.enter_scope();
+	$$ = Value::None;
+	} caselist[cases] {
+	// The scope is left before the '}' is shifted, so that the token after it is read in the outer scope
+	self.leave_scope();
+	$$ = Value::None;
+	} '}' {
+	let mut switch_node = switch_node();
+	switch_node.push_node($scrutinee.unwrap_tree());
+	for label_node in $cases.unwrap_tree().children() {
+	    switch_node.push_node(label_node.clone());
 	}
-	// TODO: Verify condition type
-	$$ = combine(while_node(), Tree(condition_node), $body);
+	$$ = Tree(switch_node);
 	}
 
+caselist:
+	/* empty */
+		{
+		$$ = Tree(sequence_node());
+		}
+	| caselist[list] caselabel[label] statementlist[body]
+		{
+		let mut list_node = $list.unwrap_tree();
+		let mut label_node = $label.unwrap_tree();
+		label_node.push_node($body.unwrap_tree());
+		list_node.push_node(label_node);
+		$$ = Tree(list_node);
+		}
+
+caselabel:
+	KW_CASE simpexpr[label] ':'
+		{
+		// The value of the label is evaluated by the type checker
+		let mut case_node = case_node(0);
+		case_node.push_node($label.unwrap_tree());
+		$$ = Tree(case_node);
+		}
+	| KW_DEFAULT ':'
+		{
+		$$ = Tree(default_node());
+		}
+
 returnstatement:
 	KW_RETURN {
 	// It's a void return
-	match self.symbol_table.function_type().unwrap() {
-	    SymbolType::Void => {
-
-	    }
-	    _ => {
-            return self.report_semantic_error("return must be of type void");
-	    }
-	}
-	    $$ = Tree(return_node(SymbolType::Void));
-	// TODO: Handle invalid return type
+	$$ = Tree(return_node(SymbolType::Void));
 	}
 	| KW_RETURN assignment[expr] {
 	// It's a value return
-	let expr_node = $expr.unwrap_tree();
-	let return_type = expr_node.value().symbol_type();
-	let function_type = self.symbol_table.function_type().unwrap();
-
-	if !match_types(function_type, return_type) {
-	    let error = format!("expected return type {}, got {}", &function_type, &return_type);
-	    return self.report_semantic_error(&error);
-	    // TODO: Handle non-matching return types
-	} else if function_type != return_type {
-		// The assignment performs a type cast, e.g. int to float
-		// The cast node becomes the parent of the expression node
-		let mut cast_node = cast_node(function_type);
-		cast_node.push_node(expr_node);
-
-		// The return node becomes the parent of the cast node
-		let mut parent = return_node(return_type);
-		parent.push_node(cast_node);
-
-		$$ = Tree(parent);
-	} else {
-		// The return node becomes the parent of the expression node
-		let mut parent = return_node(return_type);
-		parent.push_node(expr_node);
-
-		$$ = Tree(parent);
-	}
+	let mut return_node = return_node(SymbolType::Void);
+	return_node.push_node($expr.unwrap_tree());
+	$$ = Tree(return_node);
 	}
 
 printf:
 	KW_PRINTF '(' assignment[arg] ')'
 		{
-		let child = $arg.unwrap_tree();
-		if let SymbolType::Void = child.value().symbol_type() {
-		    let error = format!("type void not acceptable output");
-		    return self.report_semantic_error(&error);
-		}
-		// TODO: Handle printf argument type
 		let mut parent = print_node();
-		parent.push_node(child);
+		parent.push_node($arg.unwrap_tree());
 		$$ = Tree(parent);
 		}
-	| KW_PRINTF '(' CONST_STRING[arg] ')'
+	| KW_PRINTF '(' CONST_STRING[format] ',' argumentlist[args] ')'
 		{
-		let child = string_node($arg.unwrap_token().text.to_string());
+		// It's a formatted output, the format string precedes the arguments until the type checker has parsed it
 		let mut parent = print_node();
-		parent.push_node(child);
+		parent.push_node(string_node($format.unwrap_token().text));
+		for arg in $args.unwrap_tree().children() {
+		    parent.push_node(arg.clone());
+		}
 		$$ = Tree(parent);
 		}
 
 declassignment:
-	type ID[name] {
+	type identifier[name] {
 		// It's a declaration without assignment
-	        let name =$name.unwrap_name();
-	        let symbol_type = $type.unwrap_type();
-		let symbol = self.symbol_table.variable_symbol(name.clone(), symbol_type);
-		match self.symbol_table.insert(symbol) {
-		    Ok(_) => {
-		        $$ = Tree(variable_node(name))
-		    }
-		    Err(error) => {
-		    	self.add_error(error);
-    			return Ok(Self::YYERROR);
-    			}
-		}
+		self.begin_declaration($type, false);
+		$$ = Tree(self.declarator($name.unwrap_name(), *@name));
 	}
-	| type ID[name] {
-		// It's a declaration with an immediate assignment. We have to declare the variable first
-	      let name =$name.unwrap_name();
-		let symbol_type = $type.unwrap_type();
-		let symbol = self.symbol_table.variable_symbol(name.clone(), symbol_type);
-		match self.symbol_table.insert(symbol) {
-		    Ok(_) => {
-			$$ = Tree(variable_node(name))
-		    }
-		    Err(error) => {
-			self.add_error(error);
-			    return Ok(Self::YYERROR);
-			}
-		}
-	} '=' assignment[expr] {
-		// We need the variable node from the just handled declaration
-		let variable_node = $3.unwrap_tree();
-		match self.handle_assignment(Name(variable_node.value().symbol_name().unwrap()), $expr) {
-		    Ok(assignment_node) => {
-		    	// Add the variable declaration to the assignment
-		    	let mut assignment_node = assignment_node.unwrap_tree();
-		        assignment_node.prepend_node(variable_node);
-		        $$ = Tree(assignment_node);
-		    },
-		    Err(error) => {
-		    return self.report_semantic_error(&error);},
-		}
+	| type identifier[name] '=' assignment[expr] {
+		// It's a declaration with an immediate assignment
+		self.begin_declaration($type, false);
+		let variable_node = self.declarator($name.unwrap_name(), *@name);
+		$$ = Tree(initialization(variable_node, $expr.unwrap_tree()));
+	}
+	| ID[alias] identifier[name] {
+		// It's a declaration of a type alias that was read as identifier, since a scope that hid the alias had not
+		// been left yet when the name was read, e.g. after the body of a loop. The alias is resolved now.
+		let Some(declared_type) = self.hidden_type_alias($alias.unwrap_name(), *@name) else {
+		    return Err(());
+		};
+		self.begin_declaration(declared_type, false);
+		$$ = Tree(self.declarator($name.unwrap_name(), *@name));
+	}
+	| ID[alias] identifier[name] '=' assignment[expr] {
+		let Some(declared_type) = self.hidden_type_alias($alias.unwrap_name(), *@name) else {
+		    return Err(());
+		};
+		self.begin_declaration(declared_type, false);
+		let variable_node = self.declarator($name.unwrap_name(), *@name);
+		$$ = Tree(initialization(variable_node, $expr.unwrap_tree()));
+	}
+	| KW_CONST type identifier[name] {
+		// A constant must be initialized at its declaration, which is checked by the type checker
+		self.begin_declaration($type, true);
+		$$ = Tree(self.declarator($name.unwrap_name(), *@name));
+	}
+	| KW_CONST type identifier[name] '=' assignment[expr] {
+		// It's a constant declaration, the initialization is the only assignment that is allowed for a constant
+		self.begin_declaration($type, true);
+		let variable_node = self.declarator($name.unwrap_name(), *@name);
+		$$ = Tree(initialization(variable_node, $expr.unwrap_tree()));
+	}
+	| declassignment[list] ',' identifier[name] {
+		// It's a further declarator without assignment, e.g. `c` in `int a, b = 2, c`. It has the type of the first one
+		let variable_node = self.declarator($name.unwrap_name(), *@name);
+		$$ = Tree(self.group_declarators($list.unwrap_tree(), variable_node));
+	}
+	| declassignment[list] ',' identifier[name] '=' assignment[expr] {
+		// It's a further declarator with an immediate assignment
+		let variable_node = self.declarator($name.unwrap_name(), *@name);
+		let assignment_node = initialization(variable_node, $expr.unwrap_tree());
+		$$ = Tree(self.group_declarators($list.unwrap_tree(), assignment_node));
+	}
+
+identifier:
+	ID[name] {
+	$$ = $name;
+	}
+	| TYPE_NAME[name] {
+	// A declaration may reuse the name of a type alias, the alias is hidden within the scope of the declaration
+	$$ = $name;
 	}
 
 type:
@@ -606,6 +653,31 @@ type:
 	// Handling of keyword that defines the type of a function or variable
 	$$ = Value::SymbolType(SymbolType::Integer);
 	}
+	| KW_STRING {
+	// Handling of keyword that defines the type of a function or variable
+	$$ = Value::SymbolType(SymbolType::String);
+	}
+	| KW_CHAR {
+	// Handling of keyword that defines the type of a function or variable
+	$$ = Value::SymbolType(SymbolType::Char);
+	}
+	| TYPE_NAME[name] {
+	// It's an alias of another type, the alias is kept for diagnostics
+	let name = $name.unwrap_name();
+	let symbol_type = self.type_alias(&name).unwrap();
+	$$ = Value::TypeAlias(name, symbol_type);
+	}
+	| KW_STRUCT ID[name] {
+	// The struct must have been defined before it is used as a type, otherwise the type cannot be represented
+	let name = $name.unwrap_name();
+	match self.struct_names.iter().position(|struct_name| *struct_name == name) {
+	    Some(index) => $$ = Value::SymbolType(SymbolType::Struct(index)),
+	    None => {
+	        let error = format!("undefined struct {}", name);
+	        return self.report_semantic_error(&error);
+	    }
+	}
+	}
 	| KW_VOID  {
 	// Handling of keyword that defines the type of a function or variable
 	$$ = Value::SymbolType(SymbolType::Void);
@@ -613,29 +685,40 @@ type:
 
 statassignment:
 	ID[name] '=' assignment[expr] {
-		match self.handle_assignment($name, $expr) {
-		    Ok(result) => $$ = result,
-		    Err(error) => {
-		    return self.report_semantic_error(&error);
-		    }
-		}
+		$$ = combine(assign_node(SymbolType::Void), Tree(variable_ref_node($name.unwrap_name(), SymbolType::Void, 0)), $expr);
+	}
+	| memberaccess[target] '=' assignment[expr] {
+		$$ = combine(assign_node(SymbolType::Void), $target, $expr);
 	}
 
 assignment:
 	ID[name] '=' assignment[expr] {
 		// It's a variable assignment
-		match self.handle_assignment($name, $expr) {
-		    Ok(result) => $$ = result,
-		    Err(error) => {
-		    		    return self.report_semantic_error(&error);
-		    		  }
-		}
+		$$ = combine(assign_node(SymbolType::Void), Tree(variable_ref_node($name.unwrap_name(), SymbolType::Void, 0)), $expr);
 	}
-	| expr
+	| memberaccess[target] '=' assignment[expr] {
+		// It's an assignment to a member of a struct
+		$$ = combine(assign_node(SymbolType::Void), $target, $expr);
+	}
+	| conditional
 	{
-	$$ = $expr;
+	$$ = $conditional;
 	}
 
+conditional:
+	expr
+		{
+		$$ = $expr;
+		}
+	| expr[cond] '?' assignment[then] ':' conditional[else]
+		{
+		let mut parent = conditional_node(SymbolType::Void);
+		parent.push_node($cond.unwrap_tree());
+		parent.push_node($then.unwrap_tree());
+		parent.push_node($else.unwrap_tree());
+		$$ = Tree(parent);
+		}
+
 expr:
 	simpexpr
 		{
@@ -643,120 +726,79 @@ expr:
 		}
 	| simpexpr[lhs] EQ  simpexpr[rhs]
 		{
-		match self.logical_operator(eq_node, $lhs, $rhs) {
-		    Ok(value) => $$ = value,
-		    Err(error) => return self.report_semantic_error(&error),
-		}
+		$$ = operation(eq_node(), $lhs, $rhs, @$);
 		}
 	| simpexpr[lhs] NEQ simpexpr[rhs]
 		{
-		match self.logical_operator(neq_node, $lhs, $rhs) {
-                		    Ok(value) => $$ = value,
-                		    Err(error) => return self.report_semantic_error(&error),
-                		}
+		$$ = operation(neq_node(), $lhs, $rhs, @$);
 		}
 	| simpexpr[lhs] LEQ simpexpr[rhs]
 		{
-		match self.logical_operator(leq_node, $lhs, $rhs) {
-		    Ok(value) => $$ = value,
-		    Err(error) => return self.report_semantic_error(&error),
-		}
+		$$ = operation(leq_node(), $lhs, $rhs, @$);
 		}
 	| simpexpr[lhs] GEQ simpexpr[rhs]
 		{
-		match self.logical_operator(geq_node, $lhs, $rhs) {
-                    Ok(value) => $$ = value,
-                    Err(error) => return self.report_semantic_error(&error),
-                }
+		$$ = operation(geq_node(), $lhs, $rhs, @$);
 		}
 	| simpexpr[lhs] LSS simpexpr[rhs]
 		{
-		match self.logical_operator(lst_node, $lhs, $rhs) {
-                    Ok(value) => $$ = value,
-                    Err(error) => return self.report_semantic_error(&error),
-                }
+		$$ = operation(lst_node(), $lhs, $rhs, @$);
 		}
 	| simpexpr[lhs] GRT simpexpr[rhs]
 		{
-		match self.logical_operator(grt_node, $lhs, $rhs) {
-                    Ok(value) => $$ = value,
-                    Err(error) => return self.report_semantic_error(&error),
-                }
+		$$ = operation(grt_node(), $lhs, $rhs, @$);
 		}
 
 simpexpr:
 	simpexpr[lhs] '+' simpexpr[rhs]
 		{
-		match self.operator(plus_node, $lhs, $rhs) {
-                    Ok(value) => $$ = value,
-                    Err(error) => return self.report_semantic_error(&error),
-                }
+		$$ = operation(plus_node(SymbolType::Void), $lhs, $rhs, @$);
 		}
 	| simpexpr[lhs] '-' simpexpr[rhs]
 		{
-		match self.operator(minus_node, $lhs, $rhs) {
-                    Ok(value) => $$ = value,
-                    Err(error) => return self.report_semantic_error(&error),
-                }
+		$$ = operation(minus_node(SymbolType::Void), $lhs, $rhs, @$);
 		}
 	| simpexpr[lhs] OR simpexpr[rhs]
 		{
-		match self.logical_operator(log_or_node, $lhs, $rhs) {
-                    Ok(value) => $$ = value,
-                    Err(error) => return self.report_semantic_error(&error),
-                }
+		$$ = operation(log_or_node(), $lhs, $rhs, @$);
 		}
 	| simpexpr[lhs] '*' simpexpr[rhs]
 		{
-		match self.operator(times_node, $lhs, $rhs) {
-                    Ok(value) => $$ = value,
-                    Err(error) => return self.report_semantic_error(&error),
-                }
+		$$ = operation(times_node(SymbolType::Void), $lhs, $rhs, @$);
 		}
 	| simpexpr[lhs] '/' simpexpr[rhs]
 		{
-		match self.operator(divide_node, $lhs, $rhs) {
-                    Ok(value) => $$ = value,
-                    Err(error) => return self.report_semantic_error(&error),
-                }
+		$$ = operation(divide_node(SymbolType::Void), $lhs, $rhs, @$);
 		}
 	| simpexpr[lhs] AND simpexpr[rhs]
 		{
-		match self.logical_operator(log_and_node, $lhs, $rhs) {
-                    Ok(value) => $$ = value,
-                    Err(error) => return self.report_semantic_error(&error),
-                }
+		$$ = operation(log_and_node(), $lhs, $rhs, @$);
 		}
+	| '(' type ')' simpexpr[operand] %prec UMINUS {
+	// It's an explicit cast of the operand into the given type
+	let mut cast = explicit_cast_node($type.unwrap_type());
+	cast.push_node($operand.unwrap_tree());
+	cast.set_span(@$);
+	$$ = Tree(cast);
+	}
 	| '-' simpexpr[operand] %prec UMINUS {
 	// It's an unary minus followed by an expression (the operand)
-
-	// First, retrieve the operand's node
-	let tree = $operand.unwrap_tree();
-	match tree.value().symbol_type() {
-
-	SymbolType::Integer => {
-        	let mut u_minus = u_minus_node(SymbolType::Integer);
-        	u_minus.push_node(tree);
-        	$$ = Tree(u_minus);
-	}
-	SymbolType::Float  => {
-	    let mut u_minus = u_minus_node(SymbolType::Float);
-	    u_minus.push_node(tree);
-	    $$ = Tree(u_minus);
-	}
-	_ => {
-	    let error = format!("expected symbol type integer or float, got {}", tree.value().symbol_type());
-	    return self.report_semantic_error(&error);
-	    // TODO: Handle other types
-	}
-	}
-
+	let mut u_minus = u_minus_node(SymbolType::Void);
+	u_minus.push_node($operand.unwrap_tree());
+	u_minus.set_span(@$);
+	$$ = Tree(u_minus);
 	}
 	| CONST_INT[val]
 		{
 		// It's an int literal; parse it
 		    let token = $val.unwrap_token();
-		    $$ = Tree(integer_node(token.text.parse().unwrap()));
+		    match token.text.parse() {
+		        Ok(value) => $$ = Tree(integer_node(value)),
+		        Err(_) => {
+		            let error = format!("integer literal {} is out of range at {:?}", token.text, @val);
+		            return self.report_semantic_error(&error);
+		        }
+		    }
 		}
 	| CONST_FLOAT[val]
 		{
@@ -770,41 +812,51 @@ simpexpr:
 		    let token = $val.unwrap_token();
 		    $$ = Tree(boolean_node(token.text.parse().unwrap()));
 		}
+	| CONST_CHAR[val]
+		{
+		// It's a character literal, its escape sequence has already been validated by the lexer
+		    let token = $val.unwrap_token();
+		    $$ = Tree(char_node(character_literal(&token.text)));
+		}
+	| CONST_STRING[val]
+		{
+		// It's a string literal, the text is kept as it is
+		    let token = $val.unwrap_token();
+		    $$ = Tree(string_node(token.text.to_string()));
+		}
 	| functioncall
 		{
 		// Return value of functioncall rule
 		$$ = $functioncall;
 		}
 	| ID[name] {
-		// The name of a variable is expected, as this is part of an expression
-		// The variable must have been declared before.
-		let name = $name.unwrap_token().text;
-
-		match self.symbol_table.get(&name) {
-		    None => {
-			let error = format!("Undeclared symbol {}", name);
-			return self.report_semantic_error(&error);
-			}
-		    Some(symbol) => {
-		    	match &symbol.symbol_class {
-		    	    SymbolClass::Variable => {
-
-		    	    }
-		    	    _ => {
-		    	        // let error = format!("expected Variable, got {}", &symbol.symbol_class);
-		    	        // return self.report_semantic_error(&error);
-		    	    }
-		    	}
-		    	// TODO: Check for symbol class
-			$$ = Tree(variable_ref_node(name, symbol.symbol_type));
-		    }
-		}
+		// The name of a variable is expected, as this is part of an expression. The type checker resolves the name,
+		// e.g., to a named constant.
+		$$ = Tree(variable_ref_node($name.unwrap_token().text, SymbolType::Void, 0));
 	}
 	| '(' assignment ')'
 	{
 	// The return value is the value returned by the inner assignment
 	$$ = $assignment;
 	}
+	| memberaccess
+	{
+	$$ = $memberaccess;
+	}
+
+memberaccess:
+	ID[name] '.' ID[member] {
+		// The accessed struct is a variable or parameter
+		let mut member_ref = member_ref_node($member.unwrap_name(), SymbolType::Void);
+		member_ref.push_node(variable_ref_node($name.unwrap_name(), SymbolType::Void, 0));
+		$$ = Tree(member_ref);
+	}
+	| memberaccess[base] '.' ID[member] {
+		// It's the access of a member of a nested struct
+		let mut member_ref = member_ref_node($member.unwrap_name(), SymbolType::Void);
+		member_ref.push_node($base.unwrap_tree());
+		$$ = Tree(member_ref);
+	}
 
 %%
 
@@ -826,14 +878,39 @@ impl<'a> Parser<'a> {
             yyerrstatus_: 0,
             yylexer: lexer,
             errors: Vec::new(),
-            symbol_table: SymbolTable::new(),
             syntax_tree: SyntaxTree::new(NodeValue::Root),
+            names: vec![HashMap::new()],
+            previous_token_type: 0,
+            peeked_token: None,
+            struct_names: Vec::new(),
+            declaration_type: SymbolType::Void,
+            declaration_alias: None,
+            declaration_const: false,
+            strict_globals: false,
+            lint_shadowing: false,
         }
     }
 
     /// Wrapper around generated `parse` method that also
     /// extracts the `errors` field and returns it.
-    pub fn do_parse(mut self) -> AnalysisResult {
+    pub fn do_parse(self) -> AnalysisResult {
+        self.do_parse_with_warnings().0
+    }
+
+    /// Like `do_parse`, but also returns the warnings about the program, e.g., about unreachable code
+    pub fn do_parse_with_warnings(self) -> (AnalysisResult, Vec<Warning>) {
+        let mut checker = TypeChecker::new();
+        checker.strict_globals = self.strict_globals;
+        checker.lint_shadowing = self.lint_shadowing;
+        match self.do_parse_syntax() {
+            Ok(tree) => checker.check_with_warnings(&tree),
+            Err(errors) => (Err(errors), Vec::new()),
+        }
+    }
+
+    /// Parse the program without checking its semantics, i.e., the expressions of the returned tree are not typed and
+    /// its names are not resolved yet. The tree can be checked by a [TypeChecker].
+    pub fn do_parse_syntax(mut self) -> AnalysisResult {
         self.parse();
         if self.errors.is_empty() {
 	    Ok(self.syntax_tree)
@@ -842,9 +919,79 @@ impl<'a> Parser<'a> {
 	}
     }
 
-    /// Retrieve the next token from the lexer
+    /// Retrieve the next token from the lexer. The lexer cannot distinguish type aliases from other identifiers, hence
+    /// identifiers whose innermost declaration is a type alias are turned into type names here. A token is read before
+    /// the rule that precedes it is reduced, hence a token that follows a scope may be read while the scope is still
+    /// active. Blocks leave their scope before their closing brace is shifted, declarations resolve the remaining
+    /// cases, see [Self::hidden_type_alias].
+    ///
+    /// An unary minus in front of an integer literal that is out of range on its own is folded into the literal, so
+    /// that the smallest Integer `-2147483648` can be written. A minus after an identifier, a literal, or a closing
+    /// parenthesis is a binary one, e.g., in `f() -2147483648`.
     fn next_token(&mut self) -> Token {
-        self.yylexer.yylex()
+        let mut token = self.peeked_token.take().unwrap_or_else(|| self.yylexer.yylex());
+        let operands = [
+            Lexer::ID,
+            Lexer::CONST_INT,
+            Lexer::CONST_FLOAT,
+            Lexer::CONST_BOOLEAN,
+            Lexer::CONST_CHAR,
+            Lexer::CONST_STRING,
+            ')' as i32,
+        ];
+        if token.token_type == '-' as i32 && !operands.contains(&self.previous_token_type) {
+            let next = self.yylexer.yylex();
+            if next.token_type == Lexer::CONST_INT && next.text.parse::<i32>().is_err() {
+                token = Token {
+                    token_type: Lexer::CONST_INT,
+                    loc: Loc { begin: token.loc.begin, end: next.loc.end },
+                    text: format!("-{}", next.text),
+                };
+            } else {
+                self.peeked_token = Some(next);
+            }
+        }
+        if token.token_type == Lexer::ID && self.type_alias(&token.text).is_some() {
+            token.token_type = Lexer::TYPE_NAME;
+        }
+        self.previous_token_type = token.token_type;
+        token
+    }
+
+    /// Enter a new scope of names, e.g., for a block
+    fn enter_scope(&mut self) {
+	self.names.push(HashMap::new());
+    }
+
+    /// Leave the current scope of names, the names of outer scopes are visible again
+    fn leave_scope(&mut self) {
+	self.names.pop();
+    }
+
+    /// Declare a name in the current scope. It is a type alias if the type it stands for is given, otherwise it is
+    /// another declaration that hides an alias of the same name.
+    fn bind_name(&mut self, name: String, alias_type: Option<SymbolType>) {
+	self.names.last_mut().unwrap().insert(name, alias_type);
+    }
+
+    /// Return the type that the given name stands for if its innermost declaration is a type alias
+    fn type_alias(&self, name: &str) -> Option<SymbolType> {
+	self.names.iter().rev().find_map(|scope| scope.get(name)).copied().flatten()
+    }
+
+    /// Resolve the type alias of a declaration whose type name has been read as identifier, since a scope in which the
+    /// name was hidden had not been left when the name was read. Reports a syntax error if the name is no type alias,
+    /// like the parser does for an identifier that is followed by another identifier.
+    fn hidden_type_alias(&mut self, name: String, loc: Loc) -> Option<Value> {
+	match self.type_alias(&name) {
+	    Some(symbol_type) => Some(Value::TypeAlias(name, symbol_type)),
+	    None => {
+	        let error = format!("Unexpected token ID at {:?}", loc);
+	        eprintln!("Error: {}", error);
+	        self.errors.push(Error::Syntactical(error));
+	        None
+	    }
+	}
     }
 
     /// Print a syntax error and add it to the errors field
@@ -863,97 +1010,36 @@ impl<'a> Parser<'a> {
     	Err(())
     }
 
-    /// Print an error and add it to the errors field
-    fn add_error(&mut self, error: Error) {
-        eprintln!("{}", error);
-        self.errors.push(error);
+    /// Start a declaration of the given type, which may be a type alias. The type, its alias, and the qualifier apply
+    /// to all declarators of the declaration.
+    fn begin_declaration(&mut self, declared_type: Value, is_const: bool) {
+	self.declaration_alias = declared_type.type_alias();
+	self.declaration_type = declared_type.unwrap_type();
+	self.declaration_const = is_const;
     }
 
-    /// Helper function for handling the creation of assign nodes that happens at multiple points in the parser.
-    ///
-    /// This function takes two Value instances as arguments. The first argument is assumed to contain the name 
-    /// of the variable to which a value is assigned. The second argument is assumed to contain a SyntaxTree instance
-    /// with an expression as root node (see C1 syntax).  
-    fn handle_assignment(&mut self, name: Value, expr: Value) -> Result<Value, String> {
-        // This is a static assignment to a global variable outside of a function.
-	// The variable must already be declared
-	let name = name.unwrap_name();
-	match self.symbol_table.get(&name) {
-	      None => {
-		  return Err(format!("undeclared symbol {}", &name));
-	      }
-	      Some(symbol) => {
-		  // It has been declared, but is it really a variable?
-		  if symbol.is_function() {
-		      return Err(format!("cannot assign to function {}", &name));
-		  } else {
-		      let node = expr.unwrap_tree();
-		      let node_symbol_type = node.value().symbol_type();
-		      if !match_types(symbol.symbol_type, node_symbol_type) {
-			  return Err(format!("cannot assign {} to {}: {} = {}", node_symbol_type, symbol.symbol_type, &symbol.name, &node.value()));
-		      } else if symbol.symbol_type != node_symbol_type {
-			      // The assignment performs a type cast, e.g. int to float
-			      let mut cast_node = cast_node(symbol.symbol_type);
-			      cast_node.push_node(node);
-			      return Ok(combine(assign_node(symbol.symbol_type), Tree(variable_ref_node(name, symbol.symbol_type)), Tree(cast_node)));
-		      } else {
-			      return Ok(combine(assign_node(symbol.symbol_type), Tree(variable_ref_node(name, symbol.symbol_type)), Tree(node)));
-		      }
-		  }
-	      }
-	  }
+    /// Create the node of a declarator with the given name of the declaration that is currently parsed, the span refers
+    /// to its name. The name is declared in the current scope.
+    fn declarator(&mut self, name: String, span: Loc) -> SyntaxTree<NodeValue> {
+	self.bind_name(name.clone(), None);
+	let mut variable_node = variable_node(name, self.declaration_type, self.declaration_alias.clone(), self.declaration_const, 0);
+	variable_node.set_span(span);
+	variable_node
     }
 
-    /// Create a new logical operator node with a left-hand-side (lhs) and right-hand-side (rhs).
-    /// For convenience, lhs and rhs are assumed to be instances of Value that contain a SyntaxTree each
-    fn logical_operator<T>(&mut self, operator_constructor: T, lhs: Value, rhs: Value) -> Result<YYValue, String>
-    where T: Fn() -> SyntaxTree<NodeValue>
-    {
-	let lhs = lhs.unwrap_tree();
-	let rhs = rhs.unwrap_tree();
-
-	let lhs_type = lhs.value().symbol_type();
-        let rhs_type = rhs.value().symbol_type();
-        // Temporary operator node
-        let parent = operator_constructor();
-	let mut parent = match operator_type(parent.value(), lhs_type, rhs_type) {
-	    Ok(_) => {
-		operator_constructor()
-	    }
-	    Err(error) => {
-	        return Err(error);
+    /// Add a further declarator to a declaration, e.g., `b` in `int a, b;`. A declaration with a single declarator is
+    /// represented by the declarator itself, multiple declarators are grouped in a declaration list.
+    fn group_declarators(&self, declaration: SyntaxTree<NodeValue>, declarator: SyntaxTree<NodeValue>) -> SyntaxTree<NodeValue> {
+	let mut group = match declaration.value() {
+	    NodeValue::DeclarationList(_, _) => declaration,
+	    _ => {
+	        let mut group = declaration_list_node(self.declaration_type, self.declaration_const);
+	        group.push_node(declaration);
+	        group
 	    }
 	};
-
-	parent.push_node(lhs);
-	parent.push_node(rhs);
-	Ok(Tree(parent))
-    }
-
-    /// Create a new non-logical operator node with a left-hand-side (lhs) and right-hand-side (rhs).
-    /// For convenience, lhs and rhs are assumed to be instances of Value that contain a SyntaxTree each
-    fn operator<T>(&mut self, operator_constructor: T, lhs: YYValue, rhs: YYValue) -> Result<YYValue, String>
-        where T: Fn(SymbolType) -> SyntaxTree<NodeValue>
-        {
-    	let lhs = lhs.unwrap_tree();
-    	let rhs = rhs.unwrap_tree();
-
-    	let lhs_type = lhs.value().symbol_type();
-        let rhs_type = rhs.value().symbol_type();
-        // Temporary operator node
-        let parent = operator_constructor(SymbolType::Void);
-    	let mut parent = match operator_type(parent.value(), lhs_type, rhs_type) {
-    	    Ok(op_type) => {
-    		operator_constructor(op_type)
-    	    }
-    	    Err(error) => {
-    	        return Err(error);
-    	    }
-    	};
-
-    	parent.push_node(lhs);
-    	parent.push_node(rhs);
-    	Ok(Tree(parent))
+	group.push_node(declarator);
+	group
     }
 
     /// Helper function that removes a dead code warning, which would otherwise interfere with the correction of a submitted
@@ -969,64 +1055,47 @@ impl<'a> Parser<'a> {
 
 }
 
-/// Helper function for finding problems during AST parsing
-fn ast_parse_error() {
-    panic!("Was not able to parse the AST due to an incorrect parser implementation.");
+/// Return the character of a character literal, e.g., `'a'` or the escaped `'\n'`. The quotes are removed.
+fn character_literal(text: &str) -> char {
+    let mut chars = text[1..text.len() - 1].chars();
+    match (chars.next(), chars.next()) {
+        (Some('\\'), Some('n')) => '\n',
+        (Some('\\'), Some('r')) => '\r',
+        (Some('\\'), Some('t')) => '\t',
+        (Some('\\'), Some('0')) => '\0',
+        (Some('\\'), Some(escaped)) => escaped,
+        (Some(c), _) => c,
+        (None, _) => unreachable!("empty character literals are rejected by the lexer"),
+    }
 }
 
-/// Determine whether the lhs type can be assigned the rhs type. Return true if lhs is compatible with rhs (i.e., lhs = rhs;)
-fn match_types(lhs: SymbolType, rhs: SymbolType) -> bool {
-	lhs == rhs || (lhs == SymbolType::Float && rhs == SymbolType::Integer)
+/// Set the span of a statement, unless it is the declaration of a single variable. The span of a declaration refers to
+/// the name of the declared variable, which is where diagnostics about the variable point to.
+fn statement_with_span(mut statement: SyntaxTree<NodeValue>, span: Loc) -> SyntaxTree<NodeValue> {
+    if !matches!(statement.value(), NodeValue::VariableDeclaration(_, _, _, _, _)) {
+        statement.set_span(span);
+    }
+    statement
 }
 
-/// Determine the type of an operator, e.g., for `1 + 2` the `+`-operator has the result type _integer_.
-fn operator_type(operator: &NodeValue, lhs: SymbolType, rhs: SymbolType) -> Result<SymbolType, String> {
-    match operator {
-        // Only numbers allowed, operator type is integer or float
-        NodeValue::Plus(_) | NodeValue::Minus(_) | NodeValue::Times(_) | NodeValue::Divide(_) => {
-            if lhs == SymbolType::Boolean || rhs == SymbolType::Boolean {
-                Err(format!("Type boolean is not allowed for {} operator", operator))
-            } else if  lhs == SymbolType::String || rhs == SymbolType::String{
-                Err(format!("Type string is not allowed for {} operator", operator))
-            } else if lhs == SymbolType::Float || rhs == SymbolType::Float {
-                Ok(SymbolType::Float)
-            } else {
-                Ok(SymbolType::Integer)
-            }
-		}
-
-        // Only numbers allowed, operator type is boolean
-        NodeValue::Leq | NodeValue::Geq | NodeValue::Lst | NodeValue::Grt => {
-            if (lhs == SymbolType::Integer || lhs == SymbolType::Float) && (rhs == SymbolType::Integer || rhs == SymbolType::Float) {
-                Ok(SymbolType::Boolean)
-            } else {
-                Err(format!("type {} cannot be compared with {} using the {} operator", lhs, rhs, operator))
-            }
-        }
-
-        // Any type allowed, operator type is boolean
-        NodeValue::Eq | NodeValue::Neq => {
-            if (lhs == SymbolType::Integer || lhs == SymbolType::Float) && (rhs == SymbolType::Integer || rhs == SymbolType::Float) {
-                Ok(SymbolType::Boolean)
-            } else if lhs == SymbolType::Boolean && rhs == SymbolType::Boolean {
-                Ok(SymbolType::Boolean)
-            } else {
-                Err(format!("type {} cannot be compared with {} using the {} operator", lhs, rhs, operator))
-            }
-        }
+/// Create the initialization of a variable at its declaration, i.e., an assignment to the declared variable that holds
+/// the declaration as first child
+fn initialization(declaration: SyntaxTree<NodeValue>, value: SyntaxTree<NodeValue>) -> SyntaxTree<NodeValue> {
+    let name = declaration.value().symbol_name().unwrap();
+    let mut assignment = assign_node(SymbolType::Void);
+    assignment.push_node(declaration);
+    assignment.push_node(variable_ref_node(name, SymbolType::Void, 0));
+    assignment.push_node(value);
+    assignment
+}
 
-        // Only boolean allowed, operator type is boolean
-        NodeValue::LogOr | NodeValue::LogAnd => {
-            if lhs == SymbolType::Boolean && rhs == SymbolType::Boolean {
-                Ok(SymbolType::Boolean)
-            } else {
-                Err(format!("type {} cannot be compared with {} using the {} operator", lhs, rhs, operator))
-            }
-        }
-        _ => {
-            panic!("Invalid usage of combine function. Combine should only be used when the parent node is an operator")
-        }
-    }
+/// Create the node of a binary operation with a left-hand-side (lhs) and right-hand-side (rhs), the span refers to the
+/// operation in the source text. For convenience, lhs and rhs are assumed to be instances of Value that contain a
+/// SyntaxTree each.
+fn operation(parent: SyntaxTree<NodeValue>, lhs: Value, rhs: Value, span: Loc) -> Value {
+    let mut operation = combine(parent, lhs, rhs).unwrap_tree();
+    operation.set_span(span);
+    Tree(operation)
 }
 
 /// Combine three SyntaxTree instances into a single SyntaxTree. This first SyntaxTree becomes the parent of the second
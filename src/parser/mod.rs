@@ -1,4 +1,5 @@
 pub mod bison_skeleton;
+pub mod constant;
 pub mod minako_syntax;
 pub mod structures;
 pub mod syntax_c1;
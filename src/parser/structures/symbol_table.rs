@@ -1,17 +1,68 @@
-use crate::Error;
+use crate::{Error, Loc};
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 
 type SymbolMap = HashMap<String, Symbol>;
 
+/// The id of a symbol, which is unique within the whole program
+pub type SymbolId = usize;
+
+/// A builtin function with its name, return type, and the names and types of its parameters
+type BuiltinFunction = (
+    &'static str,
+    SymbolType,
+    &'static [(&'static str, SymbolType)],
+);
+
+/// The builtin functions that are available in every program. The first ones read values at runtime and do not take
+/// any parameters, the others form the math library. Since arguments are not converted, the math functions exist
+/// either for integers or for floats.
+const BUILTIN_FUNCTIONS: [BuiltinFunction; 9] = [
+    ("readBool", SymbolType::Boolean, &[]),
+    ("readFloat", SymbolType::Float, &[]),
+    ("readInt", SymbolType::Integer, &[]),
+    ("readString", SymbolType::String, &[]),
+    (
+        "abs",
+        SymbolType::Integer,
+        &[("value", SymbolType::Integer)],
+    ),
+    (
+        "max",
+        SymbolType::Integer,
+        &[("a", SymbolType::Integer), ("b", SymbolType::Integer)],
+    ),
+    (
+        "min",
+        SymbolType::Integer,
+        &[("a", SymbolType::Integer), ("b", SymbolType::Integer)],
+    ),
+    (
+        "pow",
+        SymbolType::Float,
+        &[("base", SymbolType::Float), ("exponent", SymbolType::Float)],
+    ),
+    ("sqrt", SymbolType::Float, &[("value", SymbolType::Float)]),
+];
+
 /// A struct that represents a symbol in a symbol table.
 /// Each symbol has a name, an identifier, a type, and a class
 #[derive(Debug, Clone)]
 pub struct Symbol {
     pub name: String,
-    id: usize,
+    id: SymbolId,
     pub symbol_type: SymbolType,
     pub symbol_class: SymbolClass,
+    // Whether the symbol has been declared with the `const` qualifier, i.e., it cannot be assigned to
+    pub is_const: bool,
+    // The name of the type alias the symbol has been declared with, e.g. `real` for `typedef float real;`
+    pub type_alias: Option<String>,
+    // How often the value of the symbol has been read, e.g., a variable in an expression or a called function
+    pub reads: usize,
+    // How often a value has been assigned to the symbol, including its initialization
+    pub writes: usize,
+    // The range in the source text of the name in the declaration of the symbol, if it is known
+    pub span: Option<Loc>,
 }
 
 impl PartialEq<Self> for Symbol {
@@ -19,6 +70,8 @@ impl PartialEq<Self> for Symbol {
         self.name == other.name
             && self.symbol_type == other.symbol_type
             && self.symbol_class == other.symbol_class
+            && self.is_const == other.is_const
+            && self.type_alias == other.type_alias
     }
 }
 
@@ -40,6 +93,26 @@ pub enum SymbolType {
     Integer,
     Float,
     String,
+    Char,
+    // A user-defined struct, identified by the index of its definition in the symbol table
+    Struct(usize),
+}
+
+/// The definition of a struct type with the names and types of its members in declaration order
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructDefinition {
+    pub name: String,
+    pub members: Vec<(String, SymbolType)>,
+}
+
+impl StructDefinition {
+    /// Return the type of the member with the given name, or None if the struct has no such member
+    pub fn member_type(&self, name: &str) -> Option<SymbolType> {
+        self.members
+            .iter()
+            .find(|(member, _)| member == name)
+            .map(|(_, symbol_type)| *symbol_type)
+    }
 }
 
 impl Display for SymbolType {
@@ -48,19 +121,77 @@ impl Display for SymbolType {
     }
 }
 
-/// The class of a symbol, we track functions, functions' parameters, and variables
+/// The class of a symbol, we track functions, functions' parameters, variables, named constants, and type aliases.
+/// A function that has only been declared by a prototype so far is not yet _defined_. A _builtin_ function is
+/// provided by the runtime instead of the program. A constant carries its value, e.g., an enumerator. The type of a
+/// type alias is the type it stands for.
 #[derive(Debug, Clone, PartialEq)]
 pub enum SymbolClass {
-    Function { parameters: Vec<Symbol> },
+    Function {
+        parameters: Vec<Symbol>,
+        defined: bool,
+        builtin: bool,
+    },
     Parameter,
     Variable,
+    Constant(i32),
+    TypeAlias,
 }
 
 impl Symbol {
+    /// Return the id of the symbol. Every declaration of the program has its own id, but all declarations of a
+    /// function share the id of the first one.
+    pub fn id(&self) -> SymbolId {
+        self.id
+    }
+
     /// Returns true if the symbol refers to a function, otherwise false
     pub fn is_function(&self) -> bool {
         matches!(self.symbol_class, SymbolClass::Function { .. })
     }
+
+    /// Returns true if the symbol refers to a type alias, otherwise false
+    pub fn is_type_alias(&self) -> bool {
+        matches!(self.symbol_class, SymbolClass::TypeAlias)
+    }
+
+    /// Returns true if the symbol refers to a function whose body has been parsed, otherwise false
+    pub fn is_defined_function(&self) -> bool {
+        matches!(
+            self.symbol_class,
+            SymbolClass::Function { defined: true, .. }
+        )
+    }
+
+    /// Returns true if the symbol refers to a builtin function, otherwise false
+    pub fn is_builtin_function(&self) -> bool {
+        matches!(
+            self.symbol_class,
+            SymbolClass::Function { builtin: true, .. }
+        )
+    }
+
+    /// Returns true if the symbol refers to a function with the same return type and parameter types
+    /// as the given symbol. The names of the parameters are not compared.
+    pub fn has_same_signature(&self, other: &Symbol) -> bool {
+        match (&self.symbol_class, &other.symbol_class) {
+            (
+                SymbolClass::Function { parameters, .. },
+                SymbolClass::Function {
+                    parameters: other_parameters,
+                    ..
+                },
+            ) => {
+                self.symbol_type == other.symbol_type
+                    && parameters.len() == other_parameters.len()
+                    && parameters
+                        .iter()
+                        .zip(other_parameters)
+                        .all(|(p1, p2)| p1.symbol_type == p2.symbol_type)
+            }
+            _ => false,
+        }
+    }
 }
 
 impl Display for SymbolClass {
@@ -75,12 +206,16 @@ pub struct SymbolTable {
     // Track all symbols associated with each scope. The first element represents the global scope,
     // the last element represents the current scope
     scopes: Vec<SymbolMap>,
-    // The number of symbols in the table
-    num_symbols: usize,
+    // The id of the next declared symbol, ids are not reused after leaving a scope
+    next_id: SymbolId,
     // The ids of inserted functions, from oldest to newest
-    function_ids: Vec<usize>,
+    function_ids: Vec<SymbolId>,
     // The return type of the function in which the current scope is defined
     function_type: Option<SymbolType>,
+    // The definitions of all struct types, a struct type refers to its definition by index
+    structs: Vec<StructDefinition>,
+    // The tags of the enums that have been defined in each scope, like the symbols of the scope
+    enum_tags: Vec<Vec<String>>,
 }
 
 impl SymbolTable {
@@ -90,13 +225,50 @@ impl SymbolTable {
         SymbolTable::default()
     }
 
-    /// Create and return a new symbol for a function
+    /// Create a SymTab instance that starts in the global scope, in which only the builtin functions are defined.
+    pub fn with_builtins() -> Self {
+        let mut symbol_table = SymbolTable::new();
+        for (name, symbol_type, parameters) in BUILTIN_FUNCTIONS {
+            let function = symbol_table.function_symbol(name.to_string(), symbol_type);
+            symbol_table.next_id += 1;
+            let parameters = parameters
+                .iter()
+                .map(|(name, symbol_type)| {
+                    let parameter = symbol_table.parameter_symbol(name.to_string(), *symbol_type);
+                    symbol_table.next_id += 1;
+                    parameter
+                })
+                .collect();
+            let symbol = Symbol {
+                symbol_class: SymbolClass::Function {
+                    parameters,
+                    defined: true,
+                    builtin: true,
+                },
+                ..function
+            };
+            // Builtin functions have no scope of their own, hence they are not tracked like declared functions
+            symbol_table.scopes[0].insert(symbol.name.clone(), symbol);
+        }
+        symbol_table
+    }
+
+    /// Create and return a new symbol for a function that has not been defined yet
     pub fn function_symbol(&self, name: String, symbol_type: SymbolType) -> Symbol {
         Symbol {
             name,
-            id: self.num_symbols,
+            id: self.next_id,
             symbol_type,
-            symbol_class: SymbolClass::Function { parameters: vec![] },
+            symbol_class: SymbolClass::Function {
+                parameters: vec![],
+                defined: false,
+                builtin: false,
+            },
+            is_const: false,
+            type_alias: None,
+            reads: 0,
+            writes: 0,
+            span: None,
         }
     }
 
@@ -104,9 +276,46 @@ impl SymbolTable {
     pub fn variable_symbol(&self, name: String, symbol_type: SymbolType) -> Symbol {
         Symbol {
             name,
-            id: self.num_symbols,
+            id: self.next_id,
             symbol_type,
             symbol_class: SymbolClass::Variable,
+            is_const: false,
+            type_alias: None,
+            reads: 0,
+            writes: 0,
+            span: None,
+        }
+    }
+
+    /// Create and return a new symbol for a variable that has been declared with the `const` qualifier
+    pub fn constant_symbol(&self, name: String, symbol_type: SymbolType) -> Symbol {
+        Symbol {
+            is_const: true,
+            type_alias: None,
+            ..self.variable_symbol(name, symbol_type)
+        }
+    }
+
+    /// Create and return a new symbol for an enumerator, i.e., a named integer constant with the given value
+    pub fn enumerator_symbol(&self, name: String, value: i32) -> Symbol {
+        Symbol {
+            name,
+            id: self.next_id,
+            symbol_type: SymbolType::Integer,
+            symbol_class: SymbolClass::Constant(value),
+            is_const: true,
+            type_alias: None,
+            reads: 0,
+            writes: 0,
+            span: None,
+        }
+    }
+
+    /// Create and return a new symbol for a type alias of the given type
+    pub fn type_alias_symbol(&self, name: String, symbol_type: SymbolType) -> Symbol {
+        Symbol {
+            symbol_class: SymbolClass::TypeAlias,
+            ..self.variable_symbol(name, symbol_type)
         }
     }
 
@@ -114,68 +323,133 @@ impl SymbolTable {
     pub fn parameter_symbol(&self, name: String, symbol_type: SymbolType) -> Symbol {
         Symbol {
             name,
-            id: self.num_symbols,
+            id: self.next_id,
             symbol_type,
             symbol_class: SymbolClass::Parameter,
+            is_const: false,
+            type_alias: None,
+            reads: 0,
+            writes: 0,
+            span: None,
         }
     }
 
     /// Return the type of the last declared function, aka. its return type
-    /// This method is meant to be used for comparing the return type of a function with 
+    /// This method is meant to be used for comparing the return type of a function with
     /// the value returned by a _return_ statement in the function's body.
     pub fn function_type(&self) -> Option<SymbolType> {
         self.function_type
     }
 
+    /// Return the last declared function, i.e., the function in which the current scope is defined
+    pub fn current_function(&self) -> Option<&Symbol> {
+        let id = *self.function_ids.last()?;
+        self.get_by_id(id)
+    }
+
+    /// Mark the function with the given name as defined, i.e., its body has been parsed.
+    ///
+    /// # Error
+    /// This method returns an Error result if the function has already been defined before or if
+    /// there is no function with the given name. Otherwise, it returns Ok.
+    pub fn define_function(&mut self, name: &str) -> Result<(), Error> {
+        for map in self.scopes.iter_mut().rev() {
+            if let Some(symbol) = map.get_mut(name) {
+                return match &mut symbol.symbol_class {
+                    SymbolClass::Function { defined, .. } if !*defined => {
+                        *defined = true;
+                        Ok(())
+                    }
+                    _ => Err(Error::Semantic(format!(
+                        "{} has already been defined",
+                        symbol
+                    ))),
+                };
+            }
+        }
+        Err(Error::Semantic(format!(
+            "function {} has not been declared",
+            name
+        )))
+    }
+
     /// Enter a new scope. All symbols that are added, after a new scope has been entered, will be
     /// associated with the new scope. A SymTab initially starts in the global scope.
     pub fn enter_scope(&mut self) {
         // Create a new empty map for the new scope
         self.scopes.push(HashMap::new());
+        self.enum_tags.push(vec![]);
     }
 
     /// Leave the current scope and remove all symbols associated with the scope from the symbol table.
+    /// The removed symbols are returned, e.g., to check whether they have been used.
     ///
     /// # Panics
     /// This method panics if *leave_scope* is used on the global scope.
-    pub fn leave_scope(&mut self) {
+    pub fn leave_scope(&mut self) -> Vec<Symbol> {
         if self.scopes.len() == 1 {
             panic!("Invalid state! Called *leave_scope* on the global scope.");
         }
-        // Remove all variables defined in the current scope
+        // Remove all variables and enum tags defined in the current scope
         let symbols = self.scopes.pop().unwrap();
-        self.num_symbols -= symbols.len();
+        self.enum_tags.pop();
 
         // Remove the ids of functions from the id tracker
-        symbols.iter().map(|(_, s)| &s.symbol_class).for_each(|s| {
+        symbols.values().map(|s| &s.symbol_class).for_each(|s| {
             if let SymbolClass::Function { .. } = s {
                 self.function_ids.pop();
                 self.function_type = None;
             }
         });
+        symbols.into_values().collect()
+    }
+
+    /// Return the symbols declared in the global scope, including the builtin functions
+    pub fn global_symbols(&self) -> impl Iterator<Item = &Symbol> {
+        self.scopes[0].values()
     }
 
     /// Declare a new symbol in the current scope and add it to the symbol table. If the symbol is a
-    /// parameter, it is also added to the parameter list of the last function symbol
+    /// parameter, it is also added to the parameter list of the last function symbol.
+    ///
+    /// A function may be declared multiple times, e.g., by a prototype followed by its definition.
+    /// In this case, the new declaration replaces the previous one, but keeps its id and whether the
+    /// function has already been defined or is a builtin.
     ///
     /// # Error
     /// This method returns an Error result if the given symbol has already been declared in the
-    /// current scope. Otherwise, it returns Ok.
-    pub fn insert(&mut self, symbol: Symbol) -> Result<(), Error> {
+    /// current scope. Otherwise, it returns the id of the symbol.
+    pub fn insert(&mut self, mut symbol: Symbol) -> Result<SymbolId, Error> {
         // Make sure that the symbol has not been declared in the current scope
         let current_scope = self.scopes.last_mut().unwrap();
 
-        let result = if current_scope.contains_key(&symbol.name) {
-            Err(Error::Semantic(format!(
+        let result = match current_scope.get(&symbol.name) {
+            Some(previous) if previous.is_function() && symbol.is_function() => {
+                // Replace the previous declaration of the function, calls before this declaration still count
+                symbol.id = previous.id;
+                symbol.reads = previous.reads;
+                if let SymbolClass::Function {
+                    defined, builtin, ..
+                } = &mut symbol.symbol_class
+                {
+                    *defined = previous.is_defined_function();
+                    *builtin = previous.is_builtin_function();
+                }
+                current_scope.insert(symbol.name.clone(), symbol.clone());
+                Ok(symbol.id)
+            }
+            Some(_) => Err(Error::Semantic(format!(
                 "{} has been defined twice in the current scope ({})",
                 symbol,
                 self.scopes.len()
-            )))
-        } else {
-            // Add the symbol to the current scope
-            current_scope.insert(symbol.name.clone(), symbol.clone());
-            self.num_symbols += 1;
-            Ok(())
+            ))),
+            None => {
+                // Add the symbol to the current scope
+                symbol.id = self.next_id;
+                current_scope.insert(symbol.name.clone(), symbol.clone());
+                self.next_id += 1;
+                Ok(symbol.id)
+            }
         };
 
         if result.is_ok() {
@@ -193,11 +467,13 @@ impl SymbolTable {
                         .get_by_id_mut(id)
                         .expect("Was not able to find function");
 
-                    if let SymbolClass::Function { parameters } = &mut last_function.symbol_class {
+                    if let SymbolClass::Function { parameters, .. } =
+                        &mut last_function.symbol_class
+                    {
                         parameters.push(symbol);
                     }
                 }
-                SymbolClass::Variable => {
+                SymbolClass::Variable | SymbolClass::Constant(_) | SymbolClass::TypeAlias => {
                     // Nothing to do
                 }
             }
@@ -219,8 +495,129 @@ impl SymbolTable {
         None
     }
 
+    /// Lookup a symbol by its name in the scopes enclosing the current scope, i.e., the symbol a declaration with this
+    /// name in the current scope would shadow
+    pub fn get_outer(&self, name: &str) -> Option<&Symbol> {
+        self.scopes
+            .iter()
+            .rev()
+            .skip(1)
+            .find_map(|map| map.get(name))
+    }
+
+    /// Define a new struct type with the given members and return the type.
+    ///
+    /// # Error
+    /// This method returns an Error result if a struct with the same name has already been defined
+    /// or if the struct has multiple members with the same name.
+    pub fn define_struct(
+        &mut self,
+        name: String,
+        members: Vec<(String, SymbolType)>,
+    ) -> Result<SymbolType, Error> {
+        if self.struct_type(&name).is_some() {
+            return Err(Error::Semantic(format!(
+                "struct {} has already been defined",
+                name
+            )));
+        }
+        for (index, (member, _)) in members.iter().enumerate() {
+            if members[..index].iter().any(|(other, _)| other == member) {
+                return Err(Error::Semantic(format!(
+                    "duplicate member {} in struct {}",
+                    member, name
+                )));
+            }
+        }
+        self.structs.push(StructDefinition { name, members });
+        Ok(SymbolType::Struct(self.structs.len() - 1))
+    }
+
+    /// Define an enum with the given tag in the current scope. The tag may hide an enum of an outer scope.
+    ///
+    /// # Error
+    /// This method returns an Error result if an enum with the same tag has already been defined in
+    /// the current scope.
+    pub fn define_enum(&mut self, tag: String) -> Result<(), Error> {
+        let current_tags = self.enum_tags.last_mut().unwrap();
+        if current_tags.contains(&tag) {
+            return Err(Error::Semantic(format!(
+                "enum {} has already been defined",
+                tag
+            )));
+        }
+        current_tags.push(tag);
+        Ok(())
+    }
+
+    /// Return the type of the struct with the given name, or None if no such struct has been defined
+    pub fn struct_type(&self, name: &str) -> Option<SymbolType> {
+        self.structs
+            .iter()
+            .position(|definition| definition.name == name)
+            .map(SymbolType::Struct)
+    }
+
+    /// Return the definition of the given struct type, or None if the type is not a struct
+    pub fn struct_definition(&self, symbol_type: SymbolType) -> Option<&StructDefinition> {
+        match symbol_type {
+            SymbolType::Struct(index) => self.structs.get(index),
+            _ => None,
+        }
+    }
+
+    /// Return the name of the given type for diagnostics. Unlike the type itself, which refers to its definition by
+    /// index, a struct type is named after its definition, e.g., `struct Point`.
+    pub fn type_name(&self, symbol_type: SymbolType) -> String {
+        match self.struct_definition(symbol_type) {
+            Some(definition) => format!("struct {}", definition.name),
+            None => symbol_type.to_string(),
+        }
+    }
+
+    /// Return the name of the symbol's type for diagnostics. If the symbol has been declared with a type alias, both
+    /// the alias and the underlying type are named, e.g., `real (aka Float)` or `line (aka struct Line)`.
+    pub fn symbol_type_name(&self, symbol: &Symbol) -> String {
+        match &symbol.type_alias {
+            Some(alias) => format!("{} (aka {})", alias, self.type_name(symbol.symbol_type)),
+            None => self.type_name(symbol.symbol_type),
+        }
+    }
+
+    /// Get a reference to a symbol by its id
+    fn get_by_id(&self, id: SymbolId) -> Option<&Symbol> {
+        self.scopes
+            .iter()
+            .rev()
+            .flat_map(|map| map.values())
+            .find(|symbol| symbol.id == id)
+    }
+
+    /// Record a read of the symbol with the given name in the closest scope, e.g., of a variable in an expression or
+    /// of a called function. Unknown names are ignored.
+    pub fn record_read(&mut self, name: &str) {
+        if let Some(symbol) = self.get_mut(name) {
+            symbol.reads += 1;
+        }
+    }
+
+    /// Record an assignment to the symbol with the given name in the closest scope. Unknown names are ignored.
+    pub fn record_write(&mut self, name: &str) {
+        if let Some(symbol) = self.get_mut(name) {
+            symbol.writes += 1;
+        }
+    }
+
+    /// Get a mutable reference to the symbol with the given name in the closest scope
+    fn get_mut(&mut self, name: &str) -> Option<&mut Symbol> {
+        self.scopes
+            .iter_mut()
+            .rev()
+            .find_map(|map| map.get_mut(name))
+    }
+
     /// Get a mutable reference to a symbol by its id
-    fn get_by_id_mut(&mut self, id: usize) -> Option<&mut Symbol> {
+    fn get_by_id_mut(&mut self, id: SymbolId) -> Option<&mut Symbol> {
         for map in self.scopes.iter_mut().rev() {
             for symbol in map.values_mut() {
                 if symbol.id == id {
@@ -237,7 +634,7 @@ impl Display for SymbolTable {
         let mut rows = String::new();
         for (id, scope) in self.scopes.iter().enumerate() {
             let mut sorted_values: Vec<&Symbol> = scope.values().collect();
-            sorted_values.sort_by(|s1, s2| s1.id.cmp(&s2.id));
+            sorted_values.sort_by_key(|s| s.id);
             let scope_rows: String = sorted_values
                 .iter()
                 .map(|v| format!("{}", v))
@@ -253,9 +650,11 @@ impl Default for SymbolTable {
     fn default() -> Self {
         SymbolTable {
             scopes: vec![SymbolMap::new()],
-            num_symbols: 0,
+            next_id: 0,
             function_ids: vec![],
             function_type: None,
+            structs: vec![],
+            enum_tags: vec![vec![]],
         }
     }
 }
@@ -271,6 +670,11 @@ mod tests {
             id: 0,
             symbol_type: SymbolType::Void,
             symbol_class: SymbolClass::Variable,
+            is_const: false,
+            type_alias: None,
+            reads: 0,
+            writes: 0,
+            span: None,
         }
     }
 
@@ -279,7 +683,16 @@ mod tests {
             name: "Second".to_string(),
             id: 1,
             symbol_type: SymbolType::Boolean,
-            symbol_class: SymbolClass::Function { parameters: vec![] },
+            symbol_class: SymbolClass::Function {
+                parameters: vec![],
+                defined: true,
+                builtin: false,
+            },
+            is_const: false,
+            type_alias: None,
+            reads: 0,
+            writes: 0,
+            span: None,
         }
     }
 
@@ -288,7 +701,16 @@ mod tests {
             name: "Third".to_string(),
             id: 2,
             symbol_type: SymbolType::Integer,
-            symbol_class: SymbolClass::Function { parameters: vec![] },
+            symbol_class: SymbolClass::Function {
+                parameters: vec![],
+                defined: true,
+                builtin: false,
+            },
+            is_const: false,
+            type_alias: None,
+            reads: 0,
+            writes: 0,
+            span: None,
         }
     }
 
@@ -296,7 +718,7 @@ mod tests {
     fn empty_table() {
         let sym_tab = SymbolTable::default();
         assert_eq!(sym_tab.scopes.len(), 1);
-        assert_eq!(sym_tab.scopes.get(0).unwrap().len(), 0)
+        assert_eq!(sym_tab.scopes.first().unwrap().len(), 0)
     }
 
     #[test]
@@ -304,8 +726,8 @@ mod tests {
         let mut sym_tab = SymbolTable::new();
         let symbol = first_symbol();
         sym_tab.insert(symbol.clone()).unwrap();
-        assert_eq!(sym_tab.scopes.get(0).unwrap().len(), 1);
-        assert_eq!(sym_tab.scopes.get(0).unwrap().get("First"), Some(&symbol));
+        assert_eq!(sym_tab.scopes.first().unwrap().len(), 1);
+        assert_eq!(sym_tab.scopes.first().unwrap().get("First"), Some(&symbol));
     }
 
     #[test]
@@ -315,10 +737,13 @@ mod tests {
         let symbol_b = second_symbol();
         sym_tab.insert(symbol_a.clone()).unwrap();
         sym_tab.insert(symbol_b.clone()).unwrap();
-        assert_eq!(sym_tab.scopes.get(0).unwrap().len(), 2);
-        assert_eq!(sym_tab.scopes.get(0).unwrap().get("First"), Some(&symbol_a));
+        assert_eq!(sym_tab.scopes.first().unwrap().len(), 2);
         assert_eq!(
-            sym_tab.scopes.get(0).unwrap().get("Second"),
+            sym_tab.scopes.first().unwrap().get("First"),
+            Some(&symbol_a)
+        );
+        assert_eq!(
+            sym_tab.scopes.first().unwrap().get("Second"),
             Some(&symbol_b)
         );
     }
@@ -339,11 +764,14 @@ mod tests {
         sym_tab.insert(symbol_c.clone()).unwrap();
 
         assert_eq!(sym_tab.scopes.len(), 3);
-        assert_eq!(sym_tab.scopes.get(0).unwrap().len(), 1);
+        assert_eq!(sym_tab.scopes.first().unwrap().len(), 1);
         assert_eq!(sym_tab.scopes.get(1).unwrap().len(), 1);
         assert_eq!(sym_tab.scopes.get(2).unwrap().len(), 1);
 
-        assert_eq!(sym_tab.scopes.get(0).unwrap().get("First"), Some(&symbol_a));
+        assert_eq!(
+            sym_tab.scopes.first().unwrap().get("First"),
+            Some(&symbol_a)
+        );
         assert_eq!(
             sym_tab.scopes.get(1).unwrap().get("Second"),
             Some(&symbol_b)
@@ -364,7 +792,7 @@ mod tests {
         sym_tab.insert(third_symbol()).unwrap();
 
         assert_eq!(sym_tab.scopes.len(), 3);
-        assert_eq!(sym_tab.scopes.get(0).unwrap().len(), 1);
+        assert_eq!(sym_tab.scopes.first().unwrap().len(), 1);
         assert_eq!(sym_tab.scopes.get(1).unwrap().len(), 1);
         assert_eq!(sym_tab.scopes.get(2).unwrap().len(), 1);
 
@@ -394,6 +822,153 @@ mod tests {
         assert_eq!(sym_tab.get("Third"), Some(&symbol_c));
     }
 
+    #[test]
+    fn redeclare_function() {
+        let mut sym_tab = SymbolTable::new();
+        let prototype = sym_tab.function_symbol("Second".to_string(), SymbolType::Boolean);
+        sym_tab.insert(prototype).unwrap();
+        assert!(!sym_tab.get("Second").unwrap().is_defined_function());
+
+        let definition = sym_tab.function_symbol("Second".to_string(), SymbolType::Boolean);
+        sym_tab.insert(definition).unwrap();
+        sym_tab.define_function("Second").unwrap();
+        assert_eq!(sym_tab.scopes.first().unwrap().len(), 1);
+        assert_eq!(sym_tab.get("Second").unwrap().id, 0);
+        assert!(sym_tab.get("Second").unwrap().is_defined_function());
+        assert!(sym_tab.define_function("Second").is_err());
+
+        sym_tab.insert(first_symbol()).unwrap();
+        assert!(sym_tab.insert(first_symbol()).is_err());
+    }
+
+    #[test]
+    fn unique_ids() {
+        let mut sym_tab = SymbolTable::new();
+        let first = sym_tab.insert(first_symbol()).unwrap();
+        sym_tab.enter_scope();
+        let second = sym_tab.insert(first_symbol()).unwrap();
+        sym_tab.leave_scope();
+        sym_tab.enter_scope();
+        // The id of a symbol of a scope that has been left is not reused
+        let third = sym_tab.insert(first_symbol()).unwrap();
+        assert_ne!(first, second);
+        assert_ne!(second, third);
+        assert_ne!(first, third);
+        assert_eq!(sym_tab.get("First").unwrap().id(), third);
+
+        // A function keeps its id when it is declared again
+        let prototype = sym_tab.function_symbol("Second".to_string(), SymbolType::Boolean);
+        let id = sym_tab.insert(prototype).unwrap();
+        let definition = sym_tab.function_symbol("Second".to_string(), SymbolType::Boolean);
+        assert_eq!(sym_tab.insert(definition).unwrap(), id);
+    }
+
+    #[test]
+    fn builtin_functions() {
+        let sym_tab = SymbolTable::with_builtins();
+        assert_eq!(sym_tab.scopes.len(), 1);
+        assert_eq!(sym_tab.function_ids.len(), 0);
+
+        let read_int = sym_tab.get("readInt").unwrap();
+        assert_eq!(read_int.symbol_type, SymbolType::Integer);
+        assert!(read_int.is_defined_function());
+        assert!(read_int.is_builtin_function());
+        assert!(SymbolTable::new().get("readInt").is_none());
+
+        let pow = sym_tab.get("pow").unwrap();
+        assert_eq!(pow.symbol_type, SymbolType::Float);
+        assert!(pow.is_builtin_function());
+        if let SymbolClass::Function { parameters, .. } = &pow.symbol_class {
+            let types: Vec<SymbolType> = parameters.iter().map(|p| p.symbol_type).collect();
+            assert_eq!(types, vec![SymbolType::Float, SymbolType::Float]);
+        } else {
+            panic!("Expected function, found {}", pow);
+        }
+    }
+
+    #[test]
+    fn define_structs() {
+        let mut sym_tab = SymbolTable::new();
+        let members = vec![
+            ("x".to_string(), SymbolType::Float),
+            ("y".to_string(), SymbolType::Float),
+        ];
+        let point = sym_tab.define_struct("Point".to_string(), members).unwrap();
+        assert_eq!(sym_tab.struct_type("Point"), Some(point));
+        assert_eq!(sym_tab.struct_type("Line"), None);
+
+        let definition = sym_tab.struct_definition(point).unwrap();
+        assert_eq!(definition.member_type("y"), Some(SymbolType::Float));
+        assert_eq!(definition.member_type("z"), None);
+        assert_eq!(sym_tab.type_name(point), "struct Point");
+        assert_eq!(sym_tab.type_name(SymbolType::Float), "Float");
+
+        let members = vec![("start".to_string(), point), ("start".to_string(), point)];
+        assert!(sym_tab.define_struct("Line".to_string(), members).is_err());
+        assert!(sym_tab.define_struct("Point".to_string(), vec![]).is_err());
+    }
+
+    #[test]
+    fn define_enums() {
+        let mut sym_tab = SymbolTable::new();
+        assert!(sym_tab.define_enum("Color".to_string()).is_ok());
+        assert!(sym_tab.define_enum("Color".to_string()).is_err());
+
+        // An inner scope may define an enum with the same tag
+        sym_tab.enter_scope();
+        assert!(sym_tab.define_enum("Color".to_string()).is_ok());
+        sym_tab.leave_scope();
+        assert!(sym_tab.define_enum("Shape".to_string()).is_ok());
+    }
+
+    #[test]
+    fn type_aliases() {
+        let mut sym_tab = SymbolTable::new();
+        let alias = sym_tab.type_alias_symbol("real".to_string(), SymbolType::Float);
+        sym_tab.insert(alias).unwrap();
+        assert!(sym_tab.get("real").unwrap().is_type_alias());
+
+        let mut variable = sym_tab.variable_symbol("r".to_string(), SymbolType::Float);
+        assert_eq!(sym_tab.symbol_type_name(&variable), "Float");
+        variable.type_alias = Some("real".to_string());
+        assert_eq!(sym_tab.symbol_type_name(&variable), "real (aka Float)");
+    }
+
+    #[test]
+    fn lookup_outer() {
+        let mut sym_tab = SymbolTable::new();
+        sym_tab.insert(first_symbol()).unwrap();
+        assert!(sym_tab.get_outer("First").is_none());
+
+        sym_tab.enter_scope();
+        sym_tab.insert(second_symbol()).unwrap();
+        assert_eq!(sym_tab.get_outer("First"), Some(&first_symbol()));
+        assert!(sym_tab.get_outer("Second").is_none());
+
+        sym_tab.enter_scope();
+        assert_eq!(sym_tab.get_outer("Second"), Some(&second_symbol()));
+    }
+
+    #[test]
+    fn count_uses() {
+        let mut sym_tab = SymbolTable::new();
+        sym_tab.insert(first_symbol()).unwrap();
+        sym_tab.enter_scope();
+        sym_tab.insert(second_symbol()).unwrap();
+
+        sym_tab.record_read("First");
+        sym_tab.record_write("Second");
+        sym_tab.record_write("Second");
+        sym_tab.record_read("Unknown");
+        assert_eq!(sym_tab.get("Second").unwrap().writes, 2);
+
+        let left = sym_tab.leave_scope();
+        assert_eq!(left.len(), 1);
+        assert_eq!((left[0].reads, left[0].writes), (0, 2));
+        let first = sym_tab.global_symbols().next().unwrap();
+        assert_eq!((first.reads, first.writes), (1, 0));
+    }
+
     #[test]
     fn lookup_closest() {
         let mut sym_tab = SymbolTable::new();
@@ -408,7 +983,16 @@ mod tests {
             name: "First".to_string(),
             id: 1,
             symbol_type: SymbolType::Float,
-            symbol_class: SymbolClass::Function { parameters: vec![] },
+            symbol_class: SymbolClass::Function {
+                parameters: vec![],
+                defined: true,
+                builtin: false,
+            },
+            is_const: false,
+            type_alias: None,
+            reads: 0,
+            writes: 0,
+            span: None,
         };
 
         sym_tab.insert(closest.clone()).unwrap();
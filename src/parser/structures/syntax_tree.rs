@@ -1,3 +1,4 @@
+use crate::Loc;
 use std::fmt::{Display, Formatter};
 
 pub type ID = usize;
@@ -9,6 +10,8 @@ pub struct SyntaxTree<T> {
     id: ID,
     value: T,
     children: Vec<SyntaxTree<T>>,
+    // The range in the source text from which the node has been parsed, if it is known
+    span: Option<Loc>,
 }
 
 /// Simple ID provider
@@ -27,6 +30,7 @@ impl<T> SyntaxTree<T> {
             id: next_id(),
             value,
             children: Vec::new(),
+            span: None,
         }
     }
 
@@ -112,6 +116,16 @@ impl<T> SyntaxTree<T> {
     pub fn children(&self) -> &Vec<SyntaxTree<T>> {
         &self.children
     }
+
+    /// Return the range in the source text from which the root of this tree has been parsed, if it is known
+    pub fn span(&self) -> Option<Loc> {
+        self.span
+    }
+
+    /// Set the range in the source text from which the root of this tree has been parsed
+    pub fn set_span(&mut self, span: Loc) {
+        self.span = Some(span);
+    }
 }
 
 impl<T: Display> SyntaxTree<T> {
@@ -185,7 +199,6 @@ mod tests {
         let mut tree = SyntaxTree::new(to_s("root"));
 
         for (child_id, child) in ["first", "second", "third"].iter().map(to_s).enumerate() {
-            let child_id = child_id;
             let mut child = SyntaxTree::new(child);
             if child_id == 0 {
                 let mut descendant1 = SyntaxTree::new(to_s("A"));
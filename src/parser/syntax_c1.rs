@@ -1,4 +1,4 @@
-use crate::parser::structures::symbol_table::SymbolType;
+use crate::parser::structures::symbol_table::{SymbolId, SymbolType};
 use crate::SyntaxTree;
 use std::fmt::{Display, Formatter};
 
@@ -13,30 +13,67 @@ pub enum NodeValue {
     Float(f64),
     Boolean(bool),
     String(String),
+    Char(char),
 
     // Other
     Program,
-    // The String should hold the name of the variable
-    VariableDeclaration(String),
-    // The String should hold the name and the SymbolType the type of the variable
-    VariableRef(String, SymbolType),
-    // Cast into the carried type
-    Cast(SymbolType),
-    // Parameter with a name
-    Parameter(String),
+    // The String should hold the name of the variable, followed by its declared type, the type alias it has been
+    // declared with, whether it is constant, and the id of its symbol
+    VariableDeclaration(String, SymbolType, Option<String>, bool, SymbolId),
+    // The String should hold the name and the SymbolType the type of the variable, the id refers to its declaration
+    VariableRef(String, SymbolType, SymbolId),
+    // Declaration with multiple declarators, e.g. `int a, b = 2;`, with their type and whether they are constant.
+    // The children are the declarators, i.e., variable declarations and their initializations
+    DeclarationList(SymbolType, bool),
+    // Cast into the carried type, the flag is set for explicit casts written in the source, e.g. `(int) x`
+    Cast(SymbolType, bool),
+    // Parameter with a name, its declared type, the type alias it has been declared with, and the id of its symbol
+    Parameter(String, SymbolType, Option<String>, SymbolId),
+    // Definition of a struct type with a name, the children are its members
+    StructDefinition(String),
+    // Member of a struct definition with its name and type
+    Member(String, SymbolType),
+    // Access of a struct member with its name and type, the child is the accessed struct
+    MemberRef(String, SymbolType),
+    // Definition of a type alias with its name and the type it stands for
+    TypeAlias(String, SymbolType),
+    // Definition of an enumeration with a name, the children are its enumerators
+    EnumDefinition(String),
+    // Enumerator of an enumeration with its name, value, and the id of its symbol. Before the type checker has
+    // evaluated it, an explicitly given value is the child of the enumerator
+    Enumerator(String, i32, SymbolId),
+    // Reference to a named integer constant with its name and value, e.g. an enumerator, the id refers to its
+    // declaration
+    ConstantRef(String, i32, SymbolId),
 
     // Statements
-    // Function declaration with a name
-    FunctionDeclaration(String),
-    // Function call with a name and return type
-    FunctionCall(String, SymbolType),
+    // Function declaration with a name, return type, the type alias of the return type, and the id of its symbol,
+    // which is shared by all declarations of the function
+    FunctionDeclaration(String, SymbolType, Option<String>, SymbolId),
+    // Function prototype with a name, return type, the type alias of the return type, and the id of its symbol, i.e.,
+    // a function declaration without a body
+    FunctionPrototype(String, SymbolType, Option<String>, SymbolId),
+    // Function call with a name and return type, the flag is set for calls of builtin functions, e.g. `readInt()`.
+    // The id refers to the declaration of the called function
+    FunctionCall(String, SymbolType, bool, SymbolId),
     // Node for representing any kind of sequence, e.g., multiple statements
     Sequence,
     If,
     For,
     DoWhile,
     While,
-    Print,
+    // Switch statement, the first child is the scrutinee, the remaining children are its labels
+    Switch,
+    // Case label with its constant value, the child holds the statements following the label. Before the type checker
+    // has evaluated it, the label is the first child
+    Case(i32),
+    // Default label, the child holds the statements following the label
+    Default,
+    Break,
+    // Print statement with the segments of its format string, e.g. `printf("x=%d", x)`. Without a format string, the
+    // list is empty and the single child is printed as it is. Before the type checker has parsed it, the format string
+    // is the first of multiple children
+    Print(Vec<FormatSegment>),
     // Assignment with the type of the value that is assigned
     Assign(SymbolType),
     // Return statement with the type of the returned value
@@ -49,6 +86,8 @@ pub enum NodeValue {
     Times(SymbolType),
     Divide(SymbolType),
     UMinus(SymbolType),
+    // Conditional operator `cond ? a : b` with the common type of both branches
+    Conditional(SymbolType),
     LogOr,
     LogAnd,
     Eq,
@@ -67,16 +106,20 @@ impl NodeValue {
             NodeValue::Float(_) => SymbolType::Float,
             NodeValue::Boolean(_) => SymbolType::Boolean,
             NodeValue::String(_) => SymbolType::String,
+            NodeValue::Char(_) => SymbolType::Char,
+            NodeValue::ConstantRef(_, _, _) => SymbolType::Integer,
             NodeValue::Return(s_type)
-            | NodeValue::VariableRef(_, s_type)
-            | NodeValue::FunctionCall(_, s_type)
-            | NodeValue::Cast(s_type)
+            | NodeValue::VariableRef(_, s_type, _)
+            | NodeValue::MemberRef(_, s_type)
+            | NodeValue::FunctionCall(_, s_type, _, _)
+            | NodeValue::Cast(s_type, _)
             | NodeValue::Assign(s_type)
             | NodeValue::Plus(s_type)
             | NodeValue::Minus(s_type)
             | NodeValue::Times(s_type)
             | NodeValue::Divide(s_type)
-            | NodeValue::UMinus(s_type) => *s_type,
+            | NodeValue::UMinus(s_type)
+            | NodeValue::Conditional(s_type) => *s_type,
             NodeValue::LogOr
             | NodeValue::LogAnd
             | NodeValue::Eq
@@ -92,11 +135,28 @@ impl NodeValue {
     /// Get the symbol name of the symbol carried by this node. Returns None if there is no symbol
     pub fn symbol_name(&self) -> Option<String> {
         match self {
-            NodeValue::VariableDeclaration(name)
-            | NodeValue::VariableRef(name, _)
-            | NodeValue::Parameter(name)
-            | NodeValue::FunctionDeclaration(name)
-            | NodeValue::FunctionCall(name, _) => Some(name.clone()),
+            NodeValue::VariableDeclaration(name, _, _, _, _)
+            | NodeValue::VariableRef(name, _, _)
+            | NodeValue::ConstantRef(name, _, _)
+            | NodeValue::Parameter(name, _, _, _)
+            | NodeValue::FunctionDeclaration(name, _, _, _)
+            | NodeValue::FunctionPrototype(name, _, _, _)
+            | NodeValue::FunctionCall(name, _, _, _) => Some(name.clone()),
+            _ => None,
+        }
+    }
+
+    /// Get the id of the symbol that is declared or referred to by this node. Returns None if there is no symbol
+    pub fn symbol_id(&self) -> Option<SymbolId> {
+        match self {
+            NodeValue::VariableDeclaration(_, _, _, _, id)
+            | NodeValue::VariableRef(_, _, id)
+            | NodeValue::ConstantRef(_, _, id)
+            | NodeValue::Parameter(_, _, _, id)
+            | NodeValue::Enumerator(_, _, id)
+            | NodeValue::FunctionDeclaration(_, _, _, id)
+            | NodeValue::FunctionPrototype(_, _, _, id)
+            | NodeValue::FunctionCall(_, _, _, id) => Some(*id),
             _ => None,
         }
     }
@@ -119,30 +179,97 @@ pub fn string_node(value: String) -> SyntaxTree<NodeValue> {
     SyntaxTree::new(NodeValue::String(value))
 }
 
+pub fn char_node(value: char) -> SyntaxTree<NodeValue> {
+    SyntaxTree::new(NodeValue::Char(value))
+}
+
 // Other
 pub fn program_node() -> SyntaxTree<NodeValue> {
     SyntaxTree::new(NodeValue::Program)
 }
 
-pub fn parameter_node(name: String) -> SyntaxTree<NodeValue> {
-    SyntaxTree::new(NodeValue::Parameter(name))
+pub fn parameter_node(
+    name: String,
+    s_type: SymbolType,
+    alias: Option<String>,
+    id: SymbolId,
+) -> SyntaxTree<NodeValue> {
+    SyntaxTree::new(NodeValue::Parameter(name, s_type, alias, id))
+}
+
+pub fn struct_definition_node(name: String) -> SyntaxTree<NodeValue> {
+    SyntaxTree::new(NodeValue::StructDefinition(name))
+}
+
+pub fn member_node(name: String, s_type: SymbolType) -> SyntaxTree<NodeValue> {
+    SyntaxTree::new(NodeValue::Member(name, s_type))
+}
+
+pub fn member_ref_node(name: String, s_type: SymbolType) -> SyntaxTree<NodeValue> {
+    SyntaxTree::new(NodeValue::MemberRef(name, s_type))
+}
+
+pub fn type_alias_node(name: String, s_type: SymbolType) -> SyntaxTree<NodeValue> {
+    SyntaxTree::new(NodeValue::TypeAlias(name, s_type))
+}
+
+pub fn enum_definition_node(name: String) -> SyntaxTree<NodeValue> {
+    SyntaxTree::new(NodeValue::EnumDefinition(name))
 }
 
-pub fn variable_node(name: String) -> SyntaxTree<NodeValue> {
-    SyntaxTree::new(NodeValue::VariableDeclaration(name))
+pub fn enumerator_node(name: String, value: i32, id: SymbolId) -> SyntaxTree<NodeValue> {
+    SyntaxTree::new(NodeValue::Enumerator(name, value, id))
 }
 
-pub fn variable_ref_node(name: String, s_type: SymbolType) -> SyntaxTree<NodeValue> {
-    SyntaxTree::new(NodeValue::VariableRef(name, s_type))
+pub fn constant_ref_node(name: String, value: i32, id: SymbolId) -> SyntaxTree<NodeValue> {
+    SyntaxTree::new(NodeValue::ConstantRef(name, value, id))
+}
+
+pub fn variable_node(
+    name: String,
+    s_type: SymbolType,
+    alias: Option<String>,
+    is_const: bool,
+    id: SymbolId,
+) -> SyntaxTree<NodeValue> {
+    SyntaxTree::new(NodeValue::VariableDeclaration(
+        name, s_type, alias, is_const, id,
+    ))
+}
+
+pub fn declaration_list_node(symbol_type: SymbolType, is_const: bool) -> SyntaxTree<NodeValue> {
+    SyntaxTree::new(NodeValue::DeclarationList(symbol_type, is_const))
+}
+
+pub fn variable_ref_node(name: String, s_type: SymbolType, id: SymbolId) -> SyntaxTree<NodeValue> {
+    SyntaxTree::new(NodeValue::VariableRef(name, s_type, id))
 }
 
 // Statements
-pub fn function_node(name: String) -> SyntaxTree<NodeValue> {
-    SyntaxTree::new(NodeValue::FunctionDeclaration(name))
+pub fn function_node(
+    name: String,
+    s_type: SymbolType,
+    alias: Option<String>,
+    id: SymbolId,
+) -> SyntaxTree<NodeValue> {
+    SyntaxTree::new(NodeValue::FunctionDeclaration(name, s_type, alias, id))
+}
+
+pub fn function_prototype_node(
+    name: String,
+    s_type: SymbolType,
+    alias: Option<String>,
+    id: SymbolId,
+) -> SyntaxTree<NodeValue> {
+    SyntaxTree::new(NodeValue::FunctionPrototype(name, s_type, alias, id))
 }
 
-pub fn function_call_node(name: String, s_type: SymbolType) -> SyntaxTree<NodeValue> {
-    SyntaxTree::new(NodeValue::FunctionCall(name, s_type))
+pub fn function_call_node(name: String, s_type: SymbolType, id: SymbolId) -> SyntaxTree<NodeValue> {
+    SyntaxTree::new(NodeValue::FunctionCall(name, s_type, false, id))
+}
+
+pub fn builtin_call_node(name: String, s_type: SymbolType, id: SymbolId) -> SyntaxTree<NodeValue> {
+    SyntaxTree::new(NodeValue::FunctionCall(name, s_type, true, id))
 }
 
 pub fn sequence_node() -> SyntaxTree<NodeValue> {
@@ -165,8 +292,28 @@ pub fn while_node() -> SyntaxTree<NodeValue> {
     SyntaxTree::new(NodeValue::While)
 }
 
+pub fn switch_node() -> SyntaxTree<NodeValue> {
+    SyntaxTree::new(NodeValue::Switch)
+}
+
+pub fn case_node(value: i32) -> SyntaxTree<NodeValue> {
+    SyntaxTree::new(NodeValue::Case(value))
+}
+
+pub fn default_node() -> SyntaxTree<NodeValue> {
+    SyntaxTree::new(NodeValue::Default)
+}
+
+pub fn break_node() -> SyntaxTree<NodeValue> {
+    SyntaxTree::new(NodeValue::Break)
+}
+
 pub fn print_node() -> SyntaxTree<NodeValue> {
-    SyntaxTree::new(NodeValue::Print)
+    SyntaxTree::new(NodeValue::Print(vec![]))
+}
+
+pub fn formatted_print_node(segments: Vec<FormatSegment>) -> SyntaxTree<NodeValue> {
+    SyntaxTree::new(NodeValue::Print(segments))
 }
 
 pub fn assign_node(symbol_type: SymbolType) -> SyntaxTree<NodeValue> {
@@ -179,7 +326,11 @@ pub fn return_node(symbol_type: SymbolType) -> SyntaxTree<NodeValue> {
 
 // Expressions
 pub fn cast_node(symbol_type: SymbolType) -> SyntaxTree<NodeValue> {
-    SyntaxTree::new(NodeValue::Cast(symbol_type))
+    SyntaxTree::new(NodeValue::Cast(symbol_type, false))
+}
+
+pub fn explicit_cast_node(symbol_type: SymbolType) -> SyntaxTree<NodeValue> {
+    SyntaxTree::new(NodeValue::Cast(symbol_type, true))
 }
 
 pub fn plus_node(symbol_type: SymbolType) -> SyntaxTree<NodeValue> {
@@ -202,6 +353,10 @@ pub fn u_minus_node(symbol_type: SymbolType) -> SyntaxTree<NodeValue> {
     SyntaxTree::new(NodeValue::UMinus(symbol_type))
 }
 
+pub fn conditional_node(symbol_type: SymbolType) -> SyntaxTree<NodeValue> {
+    SyntaxTree::new(NodeValue::Conditional(symbol_type))
+}
+
 pub fn log_or_node() -> SyntaxTree<NodeValue> {
     SyntaxTree::new(NodeValue::LogOr)
 }
@@ -234,6 +389,44 @@ pub fn grt_node() -> SyntaxTree<NodeValue> {
     SyntaxTree::new(NodeValue::Grt)
 }
 
+/// A segment of the format string of a _printf_ statement
+#[derive(Clone, Debug, PartialEq)]
+pub enum FormatSegment {
+    // Text that is printed as it is
+    Text(String),
+    // Placeholder for the next argument with its expected type, e.g. `%d` for an integer
+    Argument(SymbolType),
+}
+
+impl FormatSegment {
+    /// Return the format specifier that expects an argument of the given type, if there is one
+    pub fn specifier(symbol_type: SymbolType) -> Option<&'static str> {
+        match symbol_type {
+            SymbolType::Integer => Some("%d"),
+            SymbolType::Float => Some("%f"),
+            SymbolType::Boolean => Some("%b"),
+            SymbolType::String => Some("%s"),
+            SymbolType::Char => Some("%c"),
+            _ => None,
+        }
+    }
+}
+
+impl Display for FormatSegment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FormatSegment::Text(text) => write!(f, "{:?}", text),
+            FormatSegment::Argument(symbol_type) => {
+                write!(
+                    f,
+                    "{}",
+                    FormatSegment::specifier(*symbol_type).unwrap_or("%?")
+                )
+            }
+        }
+    }
+}
+
 impl Display for NodeValue {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let text = match self {
@@ -249,12 +442,54 @@ impl Display for NodeValue {
             NodeValue::String(v) => {
                 format!("String Literal: {}", &v)
             }
-            NodeValue::FunctionDeclaration(n) => {
+            NodeValue::Char(v) => {
+                format!("Char Literal: {:?}", &v)
+            }
+            NodeValue::DeclarationList(t, false) => {
+                format!("DeclarationList({})", &t)
+            }
+            NodeValue::DeclarationList(t, true) => {
+                format!("DeclarationList(const {})", &t)
+            }
+            NodeValue::Cast(t, false) => {
+                format!("Cast({})", &t)
+            }
+            NodeValue::Cast(t, true) => {
+                format!("Cast({}, explicit)", &t)
+            }
+            NodeValue::Print(segments) if segments.is_empty() => "Print".to_string(),
+            NodeValue::Print(segments) => {
+                let segments: Vec<String> = segments.iter().map(|s| s.to_string()).collect();
+                format!("Print: {}", segments.join(", "))
+            }
+            // The declared types of declarations are shown by the nodes that refer to them, symbol ids are not shown
+            NodeValue::VariableDeclaration(n, _, _, _, _) => {
+                format!("VariableDeclaration({:?})", &n)
+            }
+            NodeValue::VariableRef(n, t, _) => {
+                format!("VariableRef({:?}, {})", &n, &t)
+            }
+            NodeValue::ConstantRef(n, v, _) => {
+                format!("ConstantRef({:?}, {})", &n, &v)
+            }
+            NodeValue::Enumerator(n, v, _) => {
+                format!("Enumerator({:?}, {})", &n, &v)
+            }
+            NodeValue::Parameter(n, _, _, _) => {
+                format!("Parameter({:?})", &n)
+            }
+            NodeValue::FunctionDeclaration(n, _, _, _) => {
                 format!("FunctionDeclaration: {}", &n)
             }
-            NodeValue::FunctionCall(n, _) => {
+            NodeValue::FunctionPrototype(n, _, _, _) => {
+                format!("FunctionPrototype: {}", &n)
+            }
+            NodeValue::FunctionCall(n, _, false, _) => {
                 format!("FunctionCall: {}", &n)
             }
+            NodeValue::FunctionCall(n, _, true, _) => {
+                format!("FunctionCall: {} (builtin)", &n)
+            }
             _ => format!("{:?}", self),
         };
         write!(f, "{}", text)
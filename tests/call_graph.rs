@@ -0,0 +1,157 @@
+use cb_5::{CallGraph, Lexer, Parser};
+use std::fs;
+
+#[cfg(test)]
+fn call_graph(input: &str) -> CallGraph {
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    parser.debug = false;
+    let syntax_tree = parser.do_parse().unwrap();
+    CallGraph::new(&syntax_tree)
+}
+
+#[test]
+fn callers_and_callees() {
+    let graph = call_graph(
+        r"int twice(int value) {
+    return 2 * value;
+}
+
+int input() {
+    return twice(readInt());
+}
+
+void main() {
+    printf(twice(input()));
+    printf(twice(1));
+}",
+    );
+    assert_eq!(graph.functions(), vec!["twice", "input", "readInt", "main"]);
+    assert_eq!(graph.callees("main"), vec!["twice", "input"]);
+    assert_eq!(graph.callees("input"), vec!["twice", "readInt"]);
+    assert!(graph.callees("twice").is_empty());
+    assert_eq!(graph.callers("twice"), vec!["input", "main"]);
+    assert_eq!(graph.callers("readInt"), vec!["input"]);
+    assert!(graph.callers("main").is_empty());
+    assert!(graph.callees("unknown").is_empty());
+}
+
+#[test]
+fn direct_recursion() {
+    let graph = call_graph(
+        r"int factorial(int n) {
+    if (n <= 1) return 1;
+    return n * factorial(n - 1);
+}
+
+void main() {
+    printf(factorial(5));
+}",
+    );
+    assert!(graph.is_recursive("factorial"));
+    assert!(!graph.is_recursive("main"));
+    assert_eq!(graph.recursions(), vec![vec!["factorial"]]);
+    assert_eq!(
+        graph.strongly_connected_components(),
+        vec![vec!["factorial"], vec!["main"]]
+    );
+}
+
+#[test]
+fn mutual_recursion() {
+    let graph = call_graph(
+        r"bool odd(int n);
+
+bool even(int n) {
+    if (n == 0) return true;
+    return odd(n - 1);
+}
+
+bool odd(int n) {
+    if (n == 0) return false;
+    return even(n - 1);
+}
+
+void main() {
+    printf(even(4));
+}",
+    );
+    assert!(graph.is_recursive("even"));
+    assert!(graph.is_recursive("odd"));
+    assert_eq!(graph.recursions(), vec![vec!["odd", "even"]]);
+    // The callees are listed before their callers
+    assert_eq!(
+        graph.strongly_connected_components(),
+        vec![vec!["odd", "even"], vec!["main"]]
+    );
+}
+
+#[test]
+fn unreachable_from_main() {
+    let graph = call_graph(
+        r"void helper() {
+}
+
+void unused() {
+    helper();
+}
+
+void loop() {
+    loop();
+}
+
+void main() {
+}",
+    );
+    assert_eq!(
+        graph.unreachable_from_main(),
+        vec!["helper", "unused", "loop"]
+    );
+}
+
+#[test]
+fn called_by_global_initialization() {
+    let graph = call_graph(
+        r"int one() {
+    return 1;
+}
+
+int g = one();
+
+void main() {
+    printf(g);
+}",
+    );
+    // The initialization of a global variable calls the function before main
+    assert!(graph.unreachable_from_main().is_empty());
+    assert!(graph.callers("one").is_empty());
+}
+
+#[test]
+fn dot_export() {
+    let graph = call_graph(
+        r"int twice(int value) {
+    return 2 * value;
+}
+
+void main() {
+    printf(twice(readInt()));
+}",
+    );
+    assert_eq!(
+        graph.to_dot(),
+        "digraph calls {\n    \"twice\";\n    \"main\";\n    \"readInt\" [style=dashed];\n    \"main\" -> \"twice\";\n    \"main\" -> \"readInt\";\n}\n"
+    );
+}
+
+#[test]
+fn demorgan() {
+    let text = fs::read_to_string("src/parser/demorgan.c1").unwrap();
+    let graph = call_graph(text.as_str());
+    assert_eq!(
+        graph.callers("not"),
+        vec!["morgan11", "morgan12", "morgan21", "morgan22", "main"]
+    );
+    assert!(graph.recursions().is_empty());
+    assert!(graph.unreachable_from_main().is_empty());
+}
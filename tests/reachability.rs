@@ -0,0 +1,64 @@
+use cb_5::{unreachable_code_in_program, Lexer, Loc, Parser};
+
+#[cfg(test)]
+fn unreachable_spans(input: &str) -> Vec<Option<Loc>> {
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    parser.debug = false;
+    let syntax_tree = parser.do_parse().unwrap();
+    unreachable_code_in_program(&syntax_tree)
+        .iter()
+        .map(|code| code.span())
+        .collect()
+}
+
+#[test]
+fn statements_after_return() {
+    let spans = unreachable_spans(
+        r"int f(int x) {
+    return x;
+    x = 1;
+    x = 2;
+}
+
+void main() {
+    printf(f(1));
+}",
+    );
+    // Both assignments after the return are a single range
+    assert_eq!(spans, vec![Some(Loc { begin: 33, end: 50 })]);
+}
+
+#[test]
+fn excluded_branches() {
+    let spans = unreachable_spans(
+        r"void main() {
+    if (true) {
+        printf(1);
+    } else {
+        printf(2);
+    }
+    while (false) {
+        printf(3);
+    }
+}",
+    );
+    assert_eq!(spans.len(), 2, "Spans: {:?}", spans);
+}
+
+#[test]
+fn reachable_program() {
+    let spans = unreachable_spans(
+        r"int f(int x) {
+    if (x > 0) {
+        return 1;
+    }
+    return 0;
+}
+
+void main() {
+    printf(f(readInt()));
+}",
+    );
+    assert!(spans.is_empty(), "Spans: {:?}", spans);
+}
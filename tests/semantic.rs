@@ -1,4 +1,4 @@
-use cb_5::{AnalysisResult, Error, Lexer, Parser};
+use cb_5::{AnalysisResult, Error, Lexer, Parser, Warning};
 use std::fs;
 
 #[cfg(test)]
@@ -9,12 +9,64 @@ fn parse(input: &str) -> AnalysisResult {
     parser.do_parse()
 }
 
+#[cfg(test)]
+fn parse_strict(input: &str) -> AnalysisResult {
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    parser.debug = false;
+    parser.strict_globals = true;
+    parser.do_parse()
+}
+
 #[cfg(test)]
 fn parse_expect_error(input: &str) {
-    let result = parse(input);
+    expect_semantic_error(parse(input));
+}
+
+#[cfg(test)]
+fn parse_strict_expect_error(input: &str) {
+    expect_semantic_error(parse_strict(input));
+}
+
+#[cfg(test)]
+fn parse_with_warnings(input: &str) -> Vec<Warning> {
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    parser.debug = false;
+    let (result, warnings) = parser.do_parse_with_warnings();
+    assert!(result.is_ok(), "Parse result: {:?}", result.err().unwrap());
+    warnings
+}
+
+#[cfg(test)]
+fn parse_lint_shadowing(input: &str) -> Vec<Warning> {
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    parser.debug = false;
+    parser.lint_shadowing = true;
+    let (result, warnings) = parser.do_parse_with_warnings();
+    assert!(result.is_ok(), "Parse result: {:?}", result.err().unwrap());
+    warnings
+}
+
+#[cfg(test)]
+fn parse_expect_warning(input: &str, message: &str) {
+    let warnings = parse_with_warnings(input);
+    assert!(
+        warnings.iter().any(|warning| warning.message == message),
+        "Parser did not warn about {}: {:?}",
+        message,
+        warnings
+    );
+    println!("\n+++++++ Warnings Returned by Parser ++++++++");
+    println!("{:?}\n", warnings);
+}
+
+#[cfg(test)]
+fn expect_semantic_error(result: AnalysisResult) {
     assert!(result.is_err(), "Parser did not detect the error",);
     let error_list = result.err().unwrap();
-    let first_error = error_list.get(0).unwrap();
+    let first_error = error_list.first().unwrap();
     match first_error {
         Error::Lexical(_) | Error::Syntactical(_) => {
             panic!("Expected semantic error found: {}", first_error)
@@ -88,6 +140,160 @@ fn cor_sem_09() {
     assert!(result.is_ok(), "Parse result: {:?}", result.err().unwrap());
 }
 
+#[test]
+fn cor_sem_10() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-cor-sem-10.c1").unwrap();
+    let result = parse(text.as_str());
+    assert!(result.is_ok(), "Parse result: {:?}", result.err().unwrap());
+}
+
+#[test]
+fn cor_sem_11() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-cor-sem-11.c1").unwrap();
+    let result = parse(text.as_str());
+    assert!(result.is_ok(), "Parse result: {:?}", result.err().unwrap());
+}
+
+#[test]
+fn cor_sem_12() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-cor-sem-12.c1").unwrap();
+    let result = parse(text.as_str());
+    assert!(result.is_ok(), "Parse result: {:?}", result.err().unwrap());
+}
+
+#[test]
+fn cor_sem_13() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-cor-sem-13.c1").unwrap();
+    let result = parse(text.as_str());
+    assert!(result.is_ok(), "Parse result: {:?}", result.err().unwrap());
+}
+
+#[test]
+fn cor_sem_14() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-cor-sem-14.c1").unwrap();
+    let result = parse(text.as_str());
+    assert!(result.is_ok(), "Parse result: {:?}", result.err().unwrap());
+}
+
+#[test]
+fn cor_sem_15() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-cor-sem-15.c1").unwrap();
+    let result = parse(text.as_str());
+    assert!(result.is_ok(), "Parse result: {:?}", result.err().unwrap());
+}
+
+#[test]
+fn cor_sem_16() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-cor-sem-16.c1").unwrap();
+    let result = parse(text.as_str());
+    assert!(result.is_ok(), "Parse result: {:?}", result.err().unwrap());
+}
+
+#[test]
+fn cor_sem_17() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-cor-sem-17.c1").unwrap();
+    let result = parse(text.as_str());
+    assert!(result.is_ok(), "Parse result: {:?}", result.err().unwrap());
+}
+
+#[test]
+fn cor_sem_18() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-cor-sem-18.c1").unwrap();
+    let result = parse(text.as_str());
+    assert!(result.is_ok(), "Parse result: {:?}", result.err().unwrap());
+}
+
+#[test]
+fn cor_sem_19() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-cor-sem-19.c1").unwrap();
+    let result = parse(text.as_str());
+    assert!(result.is_ok(), "Parse result: {:?}", result.err().unwrap());
+}
+
+#[test]
+fn cor_sem_20() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-cor-sem-20.c1").unwrap();
+    let result = parse(text.as_str());
+    assert!(result.is_ok(), "Parse result: {:?}", result.err().unwrap());
+}
+
+#[test]
+fn cor_sem_21() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-cor-sem-21.c1").unwrap();
+    let result = parse(text.as_str());
+    assert!(result.is_ok(), "Parse result: {:?}", result.err().unwrap());
+}
+
+#[test]
+fn cor_sem_22() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-cor-sem-22.c1").unwrap();
+    let result = parse(text.as_str());
+    assert!(result.is_ok(), "Parse result: {:?}", result.err().unwrap());
+}
+
+#[test]
+fn cor_sem_23() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-cor-sem-23.c1").unwrap();
+    let result = parse_strict(text.as_str());
+    assert!(result.is_ok(), "Parse result: {:?}", result.err().unwrap());
+}
+
+#[test]
+fn cor_sem_strict_disabled() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-strict-01.c1").unwrap();
+    let result = parse(text.as_str());
+    assert!(result.is_ok(), "Parse result: {:?}", result.err().unwrap());
+}
+
+#[test]
+fn cor_sem_24() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-cor-sem-24.c1").unwrap();
+    let result = parse(text.as_str());
+    assert!(result.is_ok(), "Parse result: {:?}", result.err().unwrap());
+}
+
+#[test]
+fn cor_sem_25() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-cor-sem-25.c1").unwrap();
+    let result = parse(text.as_str());
+    assert!(result.is_ok(), "Parse result: {:?}", result.err().unwrap());
+}
+
+#[test]
+fn cor_sem_26() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-cor-sem-26.c1").unwrap();
+    let warnings = parse_with_warnings(text.as_str());
+    assert!(warnings.is_empty(), "Warnings: {:?}", warnings);
+}
+
+#[test]
+fn cor_sem_27() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-cor-sem-27.c1").unwrap();
+    let warnings = parse_with_warnings(text.as_str());
+    assert!(warnings.is_empty(), "Warnings: {:?}", warnings);
+}
+
+#[test]
+fn cor_sem_28() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-cor-sem-28.c1").unwrap();
+    let result = parse(text.as_str());
+    assert!(result.is_ok(), "Parse result: {:?}", result.err().unwrap());
+}
+
+#[test]
+fn cor_sem_29() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-cor-sem-29.c1").unwrap();
+    let result = parse(text.as_str());
+    assert!(result.is_ok(), "Parse result: {:?}", result.err().unwrap());
+}
+
+#[test]
+fn cor_sem_30() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-cor-sem-30.c1").unwrap();
+    let result = parse(text.as_str());
+    assert!(result.is_ok(), "Parse result: {:?}", result.err().unwrap());
+}
+
 #[test]
 fn err_sem_assignmenttype_01() {
     let text =
@@ -350,6 +556,31 @@ fn err_sem_functioncall_14() {
     parse_expect_error(text.as_str());
 }
 
+#[test]
+fn err_sem_functioncall_15() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-err-sem-functioncall-15.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_functioncall_16() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-err-sem-functioncall-16.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_function_value_message() {
+    let text = "int f() { return 1; }\nvoid main() {\n    int a = f;\n}";
+    let errors = parse(text).err().unwrap();
+    assert!(
+        matches!(&errors[0], Error::Semantic(message) if message == "cannot use function f as a value"),
+        "Errors: {:?}",
+        errors
+    );
+}
+
 #[test]
 fn err_sem_main_01() {
     let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-main-01.c1").unwrap();
@@ -475,6 +706,56 @@ fn err_sem_printf_02() {
     parse_expect_error(text.as_str());
 }
 
+#[test]
+fn err_sem_printf_03() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-printf-03.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_printf_04() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-printf-04.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_printf_05() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-printf-05.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_printf_06() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-printf-06.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_printf_07() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-printf-07.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn cor_sem_printf_percent() {
+    // A `%` that starts no placeholder is printed as it is
+    let text = "void main() {\n    printf(\"100%\");\n    printf(\"50% off\");\n}";
+    let result = parse(text);
+    assert!(result.is_ok(), "Parse result: {:?}", result.err().unwrap());
+}
+
+#[test]
+fn err_sem_printf_08() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-printf-08.c1").unwrap();
+    let errors = parse(text.as_str()).err().unwrap();
+    // The string is printed on its own, but its placeholder lacks an argument
+    assert!(
+        matches!(&errors[0], Error::Semantic(message) if message == "format string \"%d\" expected 1 argument, got 0"),
+        "Errors: {:?}",
+        errors
+    );
+}
+
 #[test]
 fn err_sem_statement_01() {
     let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-statement-01.c1").unwrap();
@@ -546,3 +827,897 @@ fn err_sem_statement_12() {
     let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-statement-12.c1").unwrap();
     parse_expect_error(text.as_str());
 }
+
+#[test]
+fn err_sem_switch_01() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-switch-01.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_switch_02() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-switch-02.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_switch_03() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-switch-03.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_switch_04() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-switch-04.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_switch_05() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-switch-05.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_switch_06() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-switch-06.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_switch_07() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-switch-07.c1").unwrap();
+    let errors = parse(text.as_str()).err().unwrap();
+    // The break leaves the loop, not the switch
+    assert!(
+        matches!(&errors[0], Error::Semantic(message) if message == "break statement not within switch"),
+        "Errors: {:?}",
+        errors
+    );
+}
+
+#[test]
+fn err_sem_conditional_01() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-err-sem-conditional-01.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_conditional_02() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-err-sem-conditional-02.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_conditional_03() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-err-sem-conditional-03.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_prototype_01() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-prototype-01.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_prototype_02() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-prototype-02.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_prototype_03() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-prototype-03.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_prototype_04() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-prototype-04.c1").unwrap();
+    let errors = parse(text.as_str()).err().unwrap();
+    assert!(
+        matches!(&errors[0], Error::Semantic(message) if message == "function square has already been defined"),
+        "Errors: {:?}",
+        errors
+    );
+}
+
+#[test]
+fn err_sem_prototype_05() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-prototype-05.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_const_01() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-const-01.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_const_02() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-const-02.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_const_03() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-const-03.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_const_04() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-const-04.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_string_01() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-string-01.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_string_02() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-string-02.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_string_03() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-string-03.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_string_04() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-string-04.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_string_05() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-string-05.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_string_06() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-string-06.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_cast_01() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-cast-01.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_cast_02() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-cast-02.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_cast_03() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-cast-03.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_cast_04() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-cast-04.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_builtin_01() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-builtin-01.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_builtin_02() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-builtin-02.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_builtin_03() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-builtin-03.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_builtin_04() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-builtin-04.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_builtin_05() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-builtin-05.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_builtin_06() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-builtin-06.c1").unwrap();
+    let errors = parse(text.as_str()).err().unwrap();
+    assert!(
+        matches!(&errors[0], Error::Semantic(message) if message == "cannot redefine builtin function abs"),
+        "Errors: {:?}",
+        errors
+    );
+}
+
+#[test]
+fn err_sem_builtin_07() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-builtin-07.c1").unwrap();
+    let errors = parse(text.as_str()).err().unwrap();
+    assert!(
+        matches!(&errors[0], Error::Semantic(message) if message == "cannot redefine builtin function pow"),
+        "Errors: {:?}",
+        errors
+    );
+}
+
+#[test]
+fn err_sem_struct_01() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-struct-01.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_struct_02() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-struct-02.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_struct_03() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-struct-03.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_struct_04() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-struct-04.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_struct_05() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-struct-05.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_struct_06() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-struct-06.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_struct_07() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-struct-07.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_struct_08() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-struct-08.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_struct_09() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-struct-09.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_struct_type_name() {
+    let text = "struct Point {\n    int x;\n};\nvoid main() {\n    struct Point p;\n    printf(p ? 1 : 2);\n}";
+    let errors = parse(text).err().unwrap();
+    // The struct type is named after its definition
+    assert!(
+        matches!(&errors[0], Error::Semantic(message) if message == "condition must be boolean, got struct Point"),
+        "Errors: {:?}",
+        errors
+    );
+}
+
+#[test]
+fn err_sem_enum_01() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-enum-01.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_enum_02() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-enum-02.c1").unwrap();
+    let errors = parse(text.as_str()).err().unwrap();
+    assert!(
+        matches!(&errors[0], Error::Semantic(message) if message == "RED has already been declared"),
+        "Errors: {:?}",
+        errors
+    );
+}
+
+#[test]
+fn err_sem_enum_03() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-enum-03.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_enum_04() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-enum-04.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_enum_05() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-enum-05.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_enum_06() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-enum-06.c1").unwrap();
+    let errors = parse(text.as_str()).err().unwrap();
+    assert!(
+        matches!(&errors[0], Error::Semantic(message) if message == "enum Color has already been defined"),
+        "Errors: {:?}",
+        errors
+    );
+}
+
+#[test]
+fn err_sem_char_01() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-char-01.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_char_02() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-char-02.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_char_03() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-char-03.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_char_04() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-char-04.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_declarators_01() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-err-sem-declarators-01.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_declarators_02() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-err-sem-declarators-02.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_declarators_03() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-err-sem-declarators-03.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_declarators_04() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-err-sem-declarators-04.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_typedef_01() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-typedef-01.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_typedef_02() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-typedef-02.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_typedef_03() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-typedef-03.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_typedef_04() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-typedef-04.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_typedef_05() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-typedef-05.c1").unwrap();
+    let errors = parse(text.as_str()).err().unwrap();
+    // The type of the parameter is named after its alias
+    assert!(
+        matches!(&errors[0], Error::Semantic(message) if message == "incorrect argument type for parameter value of half: expected real (aka Float), got Integer"),
+        "Errors: {:?}",
+        errors
+    );
+}
+
+#[test]
+fn err_sem_strict_01() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-strict-01.c1").unwrap();
+    parse_strict_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_strict_02() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-strict-02.c1").unwrap();
+    parse_strict_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_strict_03() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-strict-03.c1").unwrap();
+    parse_strict_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_strict_04() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-strict-04.c1").unwrap();
+    parse_strict_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_return_01() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-return-01.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_return_02() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-return-02.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_return_03() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-return-03.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_return_04() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-return-04.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_return_05() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-return-05.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_return_06() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-err-sem-return-06.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn warn_sem_unreachable_01() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-warn-sem-unreachable-01.c1").unwrap();
+    parse_expect_warning(text.as_str(), "unreachable code");
+}
+
+#[test]
+fn warn_sem_unreachable_02() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-warn-sem-unreachable-02.c1").unwrap();
+    parse_expect_warning(text.as_str(), "unreachable code");
+}
+
+#[test]
+fn warn_sem_unreachable_03() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-warn-sem-unreachable-03.c1").unwrap();
+    parse_expect_warning(text.as_str(), "unreachable code");
+}
+
+#[test]
+fn warn_sem_unreachable_04() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-warn-sem-unreachable-04.c1").unwrap();
+    parse_expect_warning(text.as_str(), "unreachable code");
+}
+
+#[test]
+fn warn_sem_unreachable_05() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-warn-sem-unreachable-05.c1").unwrap();
+    parse_expect_warning(text.as_str(), "unreachable code");
+}
+
+#[test]
+fn warn_sem_unreachable_span() {
+    let text = "int f() {\n    return 1;\n    printf(2);\n    printf(3);\n}\nvoid main() {\n    printf(f());\n}";
+    let warnings = parse_with_warnings(text);
+    assert_eq!(warnings.len(), 1, "Warnings: {:?}", warnings);
+    let span = warnings[0].span.unwrap();
+    assert_eq!(
+        &text[span.begin as usize..span.end as usize],
+        "printf(2);\n    printf(3);"
+    );
+}
+
+#[test]
+fn warn_sem_reachable() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-cor-sem-25.c1").unwrap();
+    let warnings = parse_with_warnings(text.as_str());
+    assert!(
+        warnings
+            .iter()
+            .all(|warning| warning.message != "unreachable code"),
+        "Warnings: {:?}",
+        warnings
+    );
+}
+
+#[test]
+fn warn_sem_assignment_01() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-warn-sem-assignment-01.c1").unwrap();
+    parse_expect_warning(
+        text.as_str(),
+        "variable x may be used before it is assigned",
+    );
+}
+
+#[test]
+fn warn_sem_assignment_02() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-warn-sem-assignment-02.c1").unwrap();
+    parse_expect_warning(
+        text.as_str(),
+        "variable x may be used before it is assigned",
+    );
+}
+
+#[test]
+fn warn_sem_assignment_03() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-warn-sem-assignment-03.c1").unwrap();
+    parse_expect_warning(
+        text.as_str(),
+        "variable x may be used before it is assigned",
+    );
+}
+
+#[test]
+fn warn_sem_assignment_04() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-warn-sem-assignment-04.c1").unwrap();
+    parse_expect_warning(
+        text.as_str(),
+        "variable x may be used before it is assigned",
+    );
+}
+
+#[test]
+fn warn_sem_assignment_05() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-warn-sem-assignment-05.c1").unwrap();
+    parse_expect_warning(
+        text.as_str(),
+        "variable x may be used before it is assigned",
+    );
+}
+
+#[test]
+fn warn_sem_unused_01() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-warn-sem-unused-01.c1").unwrap();
+    parse_expect_warning(text.as_str(), "unused variable x");
+}
+
+#[test]
+fn warn_sem_unused_02() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-warn-sem-unused-02.c1").unwrap();
+    parse_expect_warning(text.as_str(), "variable y is assigned but never used");
+}
+
+#[test]
+fn warn_sem_unused_03() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-warn-sem-unused-03.c1").unwrap();
+    parse_expect_warning(text.as_str(), "unused parameter y");
+}
+
+#[test]
+fn warn_sem_unused_04() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-warn-sem-unused-04.c1").unwrap();
+    parse_expect_warning(text.as_str(), "function twice is never called");
+}
+
+#[test]
+fn warn_sem_unused_05() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-warn-sem-unused-05.c1").unwrap();
+    // The functions only call each other, but neither of them is called from main
+    parse_expect_warning(text.as_str(), "function even is never called from main");
+    parse_expect_warning(text.as_str(), "function odd is never called from main");
+}
+
+#[test]
+fn warn_sem_unused_global_initializer() {
+    let text =
+        "int one() {\n    return 1;\n}\n\nint g = one();\n\nvoid main() {\n    printf(g);\n}";
+    let warnings = parse_with_warnings(text);
+    assert!(warnings.is_empty(), "Warnings: {:?}", warnings);
+}
+
+#[test]
+fn warn_sem_unused_span() {
+    let text = "void main() {\n    int used = 1;\n    int unused;\n    printf(used);\n}";
+    let warnings = parse_with_warnings(text);
+    assert_eq!(warnings.len(), 1, "Warnings: {:?}", warnings);
+    let span = warnings[0].span.unwrap();
+    assert_eq!(&text[span.begin as usize..span.end as usize], "unused");
+}
+
+#[test]
+fn warn_sem_shadowing_01() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-warn-sem-shadowing-01.c1").unwrap();
+    let warnings = parse_lint_shadowing(text.as_str());
+    assert!(
+        warnings
+            .iter()
+            .any(|warning| warning.message == "variable a shadows the variable of an outer scope"),
+        "Warnings: {:?}",
+        warnings
+    );
+}
+
+#[test]
+fn warn_sem_shadowing_02() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-warn-sem-shadowing-02.c1").unwrap();
+    let warnings = parse_lint_shadowing(text.as_str());
+    assert!(
+        warnings
+            .iter()
+            .any(|warning| warning.message
+                == "parameter size shadows the function of an outer scope"),
+        "Warnings: {:?}",
+        warnings
+    );
+}
+
+#[test]
+fn warn_sem_shadowing_03() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-warn-sem-shadowing-03.c1").unwrap();
+    let warnings = parse_lint_shadowing(text.as_str());
+    assert!(
+        warnings
+            .iter()
+            .any(|warning| warning.message == "variable x shadows the variable of an outer scope"),
+        "Warnings: {:?}",
+        warnings
+    );
+}
+
+#[test]
+fn warn_sem_shadowing_04() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-warn-sem-shadowing-04.c1").unwrap();
+    let warnings = parse_lint_shadowing(text.as_str());
+    assert!(
+        warnings
+            .iter()
+            .any(|warning| warning.message == "variable i shadows the parameter of an outer scope"),
+        "Warnings: {:?}",
+        warnings
+    );
+}
+
+#[test]
+fn warn_sem_shadowing_spans() {
+    let text = "int count;\nvoid main() {\n    int count = 1;\n    printf(count);\n}";
+    let warnings = parse_lint_shadowing(text);
+    assert_eq!(warnings.len(), 1, "Warnings: {:?}", warnings);
+    let shadowing = &warnings[0];
+    let span = shadowing.span.unwrap();
+    let related = shadowing.related.unwrap();
+    assert_eq!(&text[span.begin as usize..span.end as usize], "count");
+    assert_eq!(span.begin, 33);
+    assert_eq!(related.begin, 4);
+}
+
+#[test]
+fn warn_sem_shadowing_disabled() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-cor-sem-08.c1").unwrap();
+    let warnings = parse_with_warnings(text.as_str());
+    assert!(
+        warnings
+            .iter()
+            .all(|warning| !warning.message.contains("shadows")),
+        "Warnings: {:?}",
+        warnings
+    );
+}
+
+#[test]
+fn warn_sem_condition_01() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-warn-sem-condition-01.c1").unwrap();
+    parse_expect_warning(text.as_str(), "condition is always false");
+}
+
+#[test]
+fn warn_sem_condition_02() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-warn-sem-condition-02.c1").unwrap();
+    parse_expect_warning(
+        text.as_str(),
+        "assignment used as condition, did you mean to compare using ==?",
+    );
+}
+
+#[test]
+fn warn_sem_condition_03() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-warn-sem-condition-03.c1").unwrap();
+    parse_expect_warning(
+        text.as_str(),
+        "comparison of Float values using == is unreliable",
+    );
+}
+
+#[test]
+fn warn_sem_condition_04() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-warn-sem-condition-04.c1").unwrap();
+    parse_expect_warning(text.as_str(), "condition is always false");
+}
+
+#[test]
+fn warn_sem_condition_span() {
+    let text = "void main() {\n    float x = 0.5;\n    printf(x != 0.5);\n}";
+    let warnings = parse_with_warnings(text);
+    assert_eq!(warnings.len(), 1, "Warnings: {:?}", warnings);
+    let span = warnings[0].span.unwrap();
+    assert_eq!(&text[span.begin as usize..span.end as usize], "x != 0.5");
+}
+
+#[test]
+fn warn_sem_condition_literal() {
+    let text = fs::read_to_string("tests/testfiles/semantic/test-cor-sem-25.c1").unwrap();
+    let warnings = parse_with_warnings(text.as_str());
+    assert!(
+        warnings
+            .iter()
+            .all(|warning| !warning.message.starts_with("condition is always")),
+        "Warnings: {:?}",
+        warnings
+    );
+}
+
+#[test]
+fn err_sem_arithmetic_01() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-err-sem-arithmetic-01.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_arithmetic_02() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-err-sem-arithmetic-02.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_arithmetic_03() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-err-sem-arithmetic-03.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_arithmetic_04() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-err-sem-arithmetic-04.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_arithmetic_05() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-err-sem-arithmetic-05.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_arithmetic_06() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-err-sem-arithmetic-06.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_arithmetic_07() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-err-sem-arithmetic-07.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_arithmetic_08() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-err-sem-arithmetic-08.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_arithmetic_09() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-err-sem-arithmetic-09.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_arithmetic_10() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-err-sem-arithmetic-10.c1").unwrap();
+    parse_expect_error(text.as_str());
+}
+
+#[test]
+fn err_sem_arithmetic_11() {
+    let text =
+        fs::read_to_string("tests/testfiles/semantic/test-err-sem-arithmetic-11.c1").unwrap();
+    let errors = parse(text.as_str()).err().unwrap();
+    // The minus after the closing parenthesis is a binary one, so the literal stands alone
+    assert!(
+        matches!(&errors[0], Error::Semantic(message) if message.starts_with("integer literal 2147483648 is out of range")),
+        "Errors: {:?}",
+        errors
+    );
+}
+
+#[test]
+fn err_sem_arithmetic_span() {
+    let text = "void main() {\n    printf(1 + 8 / (4 - 4));\n}";
+    let errors = parse(text).err().unwrap();
+    assert_eq!(errors.len(), 1, "Errors: {:?}", errors);
+    // The error refers to the division `8 / (4 - 4)`
+    assert!(
+        matches!(&errors[0], Error::Semantic(message) if message == "division by zero at 29...40"),
+        "Errors: {:?}",
+        errors
+    );
+}
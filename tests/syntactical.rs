@@ -112,3 +112,12 @@ fn err_syn_return_semicolon() {
     println!("{:?}\n", result.err().unwrap());
 }
 
+
+#[test]
+fn err_syn_typedef() {
+    let text = fs::read_to_string("tests/testfiles/syntactical/test-err-syn-typedef.c-1").unwrap();
+    let result = parse(text.as_str());
+    assert!(result.is_err(), "Parser did not detect the error",);
+    println!("\n+++++++ Error Message Returned by Parser ++++++++");
+    println!("{:?}\n", result.err().unwrap());
+}
@@ -9,6 +9,15 @@ fn parse(input: &str) -> AnalysisResult {
     parser.do_parse()
 }
 
+#[cfg(test)]
+fn parse_strict(input: &str) -> AnalysisResult {
+    let lexer = Lexer::new(input);
+    let mut parser = Parser::new(lexer);
+    parser.debug = false;
+    parser.strict_globals = true;
+    parser.do_parse()
+}
+
 #[test]
 fn cor_syn_assignment() {
     let text =
@@ -96,3 +105,179 @@ fn cor_printf() {
     // println!("{}", syntax_tree);
     assert_eq!(String::from("Root\n[\n  Program\n  [\n    Sequence\n    [\n      FunctionDeclaration: main\n      [\n        Sequence\n        [\n          Assign(Integer)\n          [\n            VariableDeclaration(\"a\"),\n            VariableRef(\"a\", Integer),\n            Integer Literal: 2\n          ],\n          Print\n          [\n            String Literal: \"test string\"\n          ],\n          Print\n          [\n            Plus(Integer)\n            [\n              VariableRef(\"a\", Integer),\n              Integer Literal: 1\n            ]\n          ]\n        ]\n      ]\n    ]\n  ]\n]"), syntax_tree.print());
 }
+
+#[test]
+fn cor_switch() {
+    let text = r#"
+    void main() {
+        int a = 1;
+        switch (a) {
+        case 1:
+            printf(a);
+            break;
+        default:
+            a = 2;
+        }
+    }"#;
+    let syntax_tree = parse(text).unwrap();
+    // println!("{}", syntax_tree);
+    assert_eq!(String::from("Root\n[\n  Program\n  [\n    Sequence\n    [\n      FunctionDeclaration: main\n      [\n        Sequence\n        [\n          Assign(Integer)\n          [\n            VariableDeclaration(\"a\"),\n            VariableRef(\"a\", Integer),\n            Integer Literal: 1\n          ],\n          Switch\n          [\n            VariableRef(\"a\", Integer),\n            Case(1)\n            [\n              Sequence\n              [\n                Print\n                [\n                  VariableRef(\"a\", Integer)\n                ],\n                Break\n              ]\n            ],\n            Default\n            [\n              Sequence\n              [\n                Assign(Integer)\n                [\n                  VariableRef(\"a\", Integer),\n                  Integer Literal: 2\n                ]\n              ]\n            ]\n          ]\n        ]\n      ]\n    ]\n  ]\n]"), syntax_tree.print());
+}
+
+#[test]
+fn cor_conditional() {
+    let text = r#"
+    void main() {
+        bool b = true;
+        float f = b ? 1 : 2.5;
+    }"#;
+    let syntax_tree = parse(text).unwrap();
+    // println!("{}", syntax_tree);
+    assert_eq!(String::from("Root\n[\n  Program\n  [\n    Sequence\n    [\n      FunctionDeclaration: main\n      [\n        Sequence\n        [\n          Assign(Boolean)\n          [\n            VariableDeclaration(\"b\"),\n            VariableRef(\"b\", Boolean),\n            Boolean Literal: true\n          ],\n          Assign(Float)\n          [\n            VariableDeclaration(\"f\"),\n            VariableRef(\"f\", Float),\n            Conditional(Float)\n            [\n              VariableRef(\"b\", Boolean),\n              Cast(Float)\n              [\n                Integer Literal: 1\n              ],\n              Float Literal: 2.5\n            ]\n          ]\n        ]\n      ]\n    ]\n  ]\n]"), syntax_tree.print());
+}
+
+#[test]
+fn cor_string() {
+    let text = r#"
+    void main() {
+        string s = "a" + "b";
+        printf(s == "ab");
+    }"#;
+    let syntax_tree = parse(text).unwrap();
+    // println!("{}", syntax_tree);
+    assert_eq!(String::from("Root\n[\n  Program\n  [\n    Sequence\n    [\n      FunctionDeclaration: main\n      [\n        Sequence\n        [\n          Assign(String)\n          [\n            VariableDeclaration(\"s\"),\n            VariableRef(\"s\", String),\n            Plus(String)\n            [\n              String Literal: \"a\",\n              String Literal: \"b\"\n            ]\n          ],\n          Print\n          [\n            Eq\n            [\n              VariableRef(\"s\", String),\n              String Literal: \"ab\"\n            ]\n          ]\n        ]\n      ]\n    ]\n  ]\n]"), syntax_tree.print());
+}
+
+#[test]
+fn cor_explicit_cast() {
+    let text = r#"
+    void main() {
+        float f = 2.5;
+        int i = (int) f * 2;
+        f = i;
+    }"#;
+    let syntax_tree = parse(text).unwrap();
+    // println!("{}", syntax_tree);
+    assert_eq!(String::from("Root\n[\n  Program\n  [\n    Sequence\n    [\n      FunctionDeclaration: main\n      [\n        Sequence\n        [\n          Assign(Float)\n          [\n            VariableDeclaration(\"f\"),\n            VariableRef(\"f\", Float),\n            Float Literal: 2.5\n          ],\n          Assign(Integer)\n          [\n            VariableDeclaration(\"i\"),\n            VariableRef(\"i\", Integer),\n            Times(Integer)\n            [\n              Cast(Integer, explicit)\n              [\n                VariableRef(\"f\", Float)\n              ],\n              Integer Literal: 2\n            ]\n          ],\n          Assign(Float)\n          [\n            VariableRef(\"f\", Float),\n            Cast(Float)\n            [\n              VariableRef(\"i\", Integer)\n            ]\n          ]\n        ]\n      ]\n    ]\n  ]\n]"), syntax_tree.print());
+}
+
+#[test]
+fn cor_formatted_printf() {
+    let text = r#"
+    void main() {
+        int x = 1;
+        printf("x=%d, 100%%\n", x);
+    }"#;
+    let syntax_tree = parse(text).unwrap();
+    // println!("{}", syntax_tree);
+    assert_eq!(String::from("Root\n[\n  Program\n  [\n    Sequence\n    [\n      FunctionDeclaration: main\n      [\n        Sequence\n        [\n          Assign(Integer)\n          [\n            VariableDeclaration(\"x\"),\n            VariableRef(\"x\", Integer),\n            Integer Literal: 1\n          ],\n          Print: \"x=\", %d, \", 100%\\\\n\"\n          [\n            VariableRef(\"x\", Integer)\n          ]\n        ]\n      ]\n    ]\n  ]\n]"), syntax_tree.print());
+}
+
+#[test]
+fn cor_builtin_call() {
+    let text = r#"
+    void main() {
+        int x = readInt();
+    }"#;
+    let syntax_tree = parse(text).unwrap();
+    // println!("{}", syntax_tree);
+    assert_eq!(String::from("Root\n[\n  Program\n  [\n    Sequence\n    [\n      FunctionDeclaration: main\n      [\n        Sequence\n        [\n          Assign(Integer)\n          [\n            VariableDeclaration(\"x\"),\n            VariableRef(\"x\", Integer),\n            FunctionCall: readInt (builtin)\n            [\n              Sequence\n            ]\n          ]\n        ]\n      ]\n    ]\n  ]\n]"), syntax_tree.print());
+}
+
+#[test]
+fn cor_struct() {
+    let text = r#"
+    struct Point {
+        float x;
+        int y;
+    };
+    void main() {
+        struct Point p;
+        p.x = p.y;
+    }"#;
+    let syntax_tree = parse(text).unwrap();
+    // println!("{}", syntax_tree);
+    assert_eq!(String::from("Root\n[\n  Program\n  [\n    Sequence\n    [\n      StructDefinition(\"Point\")\n      [\n        Member(\"x\", Float),\n        Member(\"y\", Integer)\n      ],\n      FunctionDeclaration: main\n      [\n        Sequence\n        [\n          VariableDeclaration(\"p\"),\n          Assign(Float)\n          [\n            MemberRef(\"x\", Float)\n            [\n              VariableRef(\"p\", Struct(0))\n            ],\n            Cast(Float)\n            [\n              MemberRef(\"y\", Integer)\n              [\n                VariableRef(\"p\", Struct(0))\n              ]\n            ]\n          ]\n        ]\n      ]\n    ]\n  ]\n]"), syntax_tree.print());
+}
+
+#[test]
+fn cor_enum() {
+    let text = r#"
+    enum Color { RED, GREEN = 5, BLUE };
+    void main() {
+        int c = BLUE;
+    }"#;
+    let syntax_tree = parse(text).unwrap();
+    // println!("{}", syntax_tree);
+    assert_eq!(String::from("Root\n[\n  Program\n  [\n    Sequence\n    [\n      EnumDefinition(\"Color\")\n      [\n        Enumerator(\"RED\", 0),\n        Enumerator(\"GREEN\", 5),\n        Enumerator(\"BLUE\", 6)\n      ],\n      FunctionDeclaration: main\n      [\n        Sequence\n        [\n          Assign(Integer)\n          [\n            VariableDeclaration(\"c\"),\n            VariableRef(\"c\", Integer),\n            ConstantRef(\"BLUE\", 6)\n          ]\n        ]\n      ]\n    ]\n  ]\n]"), syntax_tree.print());
+}
+
+#[test]
+fn cor_char() {
+    let text = r#"
+    void main() {
+        char c = '\n';
+        int i = c + 1;
+    }"#;
+    let syntax_tree = parse(text).unwrap();
+    // println!("{}", syntax_tree);
+    assert_eq!(String::from("Root\n[\n  Program\n  [\n    Sequence\n    [\n      FunctionDeclaration: main\n      [\n        Sequence\n        [\n          Assign(Char)\n          [\n            VariableDeclaration(\"c\"),\n            VariableRef(\"c\", Char),\n            Char Literal: '\\n'\n          ],\n          Assign(Integer)\n          [\n            VariableDeclaration(\"i\"),\n            VariableRef(\"i\", Integer),\n            Plus(Integer)\n            [\n              VariableRef(\"c\", Char),\n              Integer Literal: 1\n            ]\n          ]\n        ]\n      ]\n    ]\n  ]\n]"), syntax_tree.print());
+}
+
+#[test]
+fn cor_multiple_declarators() {
+    let text = r#"
+    void main() {
+        int a, b = 2, c = b;
+    }"#;
+    let syntax_tree = parse(text).unwrap();
+    // println!("{}", syntax_tree);
+    assert_eq!(String::from("Root\n[\n  Program\n  [\n    Sequence\n    [\n      FunctionDeclaration: main\n      [\n        Sequence\n        [\n          DeclarationList(Integer)\n          [\n            VariableDeclaration(\"a\"),\n            Assign(Integer)\n            [\n              VariableDeclaration(\"b\"),\n              VariableRef(\"b\", Integer),\n              Integer Literal: 2\n            ],\n            Assign(Integer)\n            [\n              VariableDeclaration(\"c\"),\n              VariableRef(\"c\", Integer),\n              VariableRef(\"b\", Integer)\n            ]\n          ]\n        ]\n      ]\n    ]\n  ]\n]"), syntax_tree.print());
+}
+
+#[test]
+fn cor_typedef() {
+    let text = r#"
+    typedef float real;
+    real half(real value) {
+        return value / 2.0;
+    }
+    void main() {
+        real r = half(1.0);
+    }"#;
+    let syntax_tree = parse(text).unwrap();
+    // println!("{}", syntax_tree);
+    assert_eq!(String::from("Root\n[\n  Program\n  [\n    Sequence\n    [\n      TypeAlias(\"real\", Float),\n      FunctionDeclaration: half\n      [\n        Sequence\n        [\n          Parameter(\"value\")\n        ],\n        Sequence\n        [\n          Return(Float)\n          [\n            Divide(Float)\n            [\n              VariableRef(\"value\", Float),\n              Float Literal: 2\n            ]\n          ]\n        ]\n      ],\n      FunctionDeclaration: main\n      [\n        Sequence\n        [\n          Assign(Float)\n          [\n            VariableDeclaration(\"r\"),\n            VariableRef(\"r\", Float),\n            FunctionCall: half\n            [\n              Sequence\n              [\n                Float Literal: 1\n              ]\n            ]\n          ]\n        ]\n      ]\n    ]\n  ]\n]"), syntax_tree.print());
+}
+
+#[test]
+fn cor_typedef_after_redeclaration() {
+    let text = r#"
+    typedef float real;
+    void f(int real) {
+        printf(real);
+    }
+    real g;
+    void main() {
+        {
+            int real = 1;
+        }
+        real y = 2.0;
+    }"#;
+    let syntax_tree = parse(text).unwrap();
+    // println!("{}", syntax_tree);
+    assert_eq!(String::from("Root\n[\n  Program\n  [\n    Sequence\n    [\n      TypeAlias(\"real\", Float),\n      FunctionDeclaration: f\n      [\n        Sequence\n        [\n          Parameter(\"real\")\n        ],\n        Sequence\n        [\n          Print\n          [\n            VariableRef(\"real\", Integer)\n          ]\n        ]\n      ],\n      VariableDeclaration(\"g\"),\n      FunctionDeclaration: main\n      [\n        Sequence\n        [\n          Sequence\n          [\n            Assign(Integer)\n            [\n              VariableDeclaration(\"real\"),\n              VariableRef(\"real\", Integer),\n              Integer Literal: 1\n            ]\n          ],\n          Assign(Float)\n          [\n            VariableDeclaration(\"y\"),\n            VariableRef(\"y\", Float),\n            Float Literal: 2\n          ]\n        ]\n      ]\n    ]\n  ]\n]"), syntax_tree.print());
+}
+
+#[test]
+fn cor_strict_globals() {
+    let text = r#"
+    const int size = 2 * 3, half = size / 2;
+    float scale = half;
+    void main() {
+        int local = size + 1;
+    }"#;
+    let syntax_tree = parse_strict(text).unwrap();
+    // println!("{}", syntax_tree);
+    assert_eq!(String::from("Root\n[\n  Program\n  [\n    Sequence\n    [\n      DeclarationList(const Integer)\n      [\n        Assign(Integer)\n        [\n          VariableDeclaration(\"size\"),\n          VariableRef(\"size\", Integer),\n          Integer Literal: 6\n        ],\n        Assign(Integer)\n        [\n          VariableDeclaration(\"half\"),\n          VariableRef(\"half\", Integer),\n          Integer Literal: 3\n        ]\n      ],\n      Assign(Float)\n      [\n        VariableDeclaration(\"scale\"),\n        VariableRef(\"scale\", Float),\n        Float Literal: 3\n      ],\n      FunctionDeclaration: main\n      [\n        Sequence\n        [\n          Assign(Integer)\n          [\n            VariableDeclaration(\"local\"),\n            VariableRef(\"local\", Integer),\n            Plus(Integer)\n            [\n              VariableRef(\"size\", Integer),\n              Integer Literal: 1\n            ]\n          ]\n        ]\n      ]\n    ]\n  ]\n]"), syntax_tree.print());
+}